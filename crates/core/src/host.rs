@@ -1,6 +1,7 @@
 //! Core reusable functionality related to hosts
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
@@ -25,8 +26,40 @@ pub struct HostData {
     pub lattice_rpc_user_jwt: String,
     #[serde(default)]
     pub lattice_rpc_user_seed: String,
+    /// URL of the NATS server for the lattice RPC connection, or a comma-separated list of URLs
+    /// (e.g. of several leaf node seeds) to fail over between. Servers with a `tls://` URL require
+    /// TLS regardless of `lattice_rpc_tls`
     #[serde(default)]
     pub lattice_rpc_url: String,
+    /// Path to a NATS credentials file used to authenticate the lattice RPC connection. Takes
+    /// precedence over `lattice_rpc_user_jwt` and `lattice_rpc_user_seed` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lattice_rpc_credsfile: Option<PathBuf>,
+    /// Whether TLS is required for the lattice RPC connection
+    #[serde(default)]
+    pub lattice_rpc_tls: bool,
+    /// Paths to PEM-encoded root certificates to trust for the lattice RPC connection, in
+    /// addition to the system roots
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lattice_rpc_tls_ca_files: Vec<PathBuf>,
+    /// Path to a PEM-encoded client certificate used to authenticate the lattice RPC connection
+    /// with mutual TLS. Must be set together with `lattice_rpc_tls_client_key`
+    ///
+    /// Providers built with the SDK reconnect with the current certificate and key whenever either
+    /// file changes or the provider receives `SIGHUP`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lattice_rpc_tls_client_cert: Option<PathBuf>,
+    /// Path to the PEM-encoded private key of `lattice_rpc_tls_client_cert`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lattice_rpc_tls_client_key: Option<PathBuf>,
+    /// Maximum number of attempts to reconnect the lattice RPC connection after it was lost. The
+    /// NATS client default is used when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lattice_rpc_max_reconnects: Option<usize>,
+    /// Upper bound of the exponential backoff between attempts to reconnect the lattice RPC
+    /// connection, in milliseconds. The NATS client default is used when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lattice_rpc_reconnect_delay_max_ms: Option<u64>,
     #[serde(default)]
     pub provider_key: String,
     #[serde(
@@ -47,6 +80,10 @@ pub struct HostData {
     /// Host-wide default RPC timeout for rpc messages, in milliseconds.  Defaults to 2000.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default_rpc_timeout_ms: Option<u64>,
+    /// Time the host waits for the provider to shut down gracefully before terminating it, in
+    /// milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_shutdown_delay_ms: Option<u64>,
     /// True if structured logging is enabled for the host. Providers should use the same setting as the host.
     #[serde(default)]
     pub structured_logging: bool,
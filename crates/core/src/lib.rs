@@ -16,6 +16,9 @@ pub use otel::*;
 pub mod rpc;
 pub use rpc::*;
 
+pub mod secrets;
+pub use secrets::*;
+
 pub mod wrpc;
 
 pub mod wit;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -11,6 +13,35 @@ pub struct HealthCheckResponse {
     /// A message containing additional information about the components health
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+    /// Structured details about the components health, e.g. backend connectivity or queue depth
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub details: HashMap<String, String>,
+}
+
+/// Response sent by a provider to a link put request, when the request has a reply subject
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LinkPutResponse {
+    /// Whether the provider accepted the link
+    #[serde(default)]
+    pub accepted: bool,
+    /// The reason the provider rejected the link, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Reason a host requests a provider to shut down
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ShutdownReason {
+    /// The reason is unknown, e.g. because the host predates shutdown reasons
+    #[default]
+    #[serde(other)]
+    Unknown,
+    /// The provider was stopped on request, e.g. because it was scaled down
+    Stop,
+    /// The host running the provider is shutting down
+    HostShutdown,
 }
 
 /// Generate the wasmbus RPC subject for putting links on a NATS cluster
@@ -31,6 +62,62 @@ pub fn link_del_subject(lattice: &str, provider_key: &str) -> String {
     format!("wasmbus.rpc.{lattice}.{provider_key}.linkdefs.del")
 }
 
+/// Generate the wasmbus RPC subject for requesting the current links of a given provider
+///
+/// Hosts running the provider reply to requests on this subject with all links the provider is
+/// the source or target of, which providers use to resynchronize link state they may have missed.
+#[must_use]
+pub fn link_get_subject(lattice: &str, provider_key: &str) -> String {
+    format!("wasmbus.rpc.{lattice}.{provider_key}.linkdefs.get")
+}
+
+/// Generate the wasmbus RPC subject for delivering updated configuration to a given provider
+///
+/// When any of the named configuration attached to a provider changes, hosts publish the newly
+/// merged configuration on this subject, which results in `receive_config_update()` being called.
+#[must_use]
+pub fn provider_config_update_subject(lattice: &str, provider_key: &str) -> String {
+    format!("wasmbus.rpc.{lattice}.{provider_key}.config.update")
+}
+
+/// Generate the wasmbus RPC subject for fetching named configuration on behalf of a given provider
+///
+/// Hosts running the provider reply to requests on this subject, whose payload is the name of the
+/// config, with the JSON-encoded config, or `null` if it does not exist.
+#[must_use]
+pub fn named_config_get_subject(lattice: &str, provider_key: &str) -> String {
+    format!("wasmbus.rpc.{lattice}.{provider_key}.config.get")
+}
+
+/// Generate the wasmbus RPC subject on which changes to the named config `config_name` are
+/// published
+///
+/// When named configuration is put or deleted through the control interface, the handling host
+/// publishes the new JSON-encoded config on this subject, or `null` if it was deleted.
+#[must_use]
+pub fn named_config_changed_subject(lattice: &str, config_name: &str) -> String {
+    format!("wasmbus.rpc.{lattice}.config.changed.{config_name}")
+}
+
+/// Generate the wasmbus RPC subject for changing the log filter of a given provider at runtime
+///
+/// Messages published on this subject carry filter directives in `RUST_LOG` syntax
+/// (e.g. `info,wasmcloud_provider_sdk=debug`), which replace the provider's current log filter.
+#[must_use]
+pub fn log_filter_subject(lattice: &str, provider_key: &str) -> String {
+    format!("wasmbus.rpc.{lattice}.{provider_key}.log_filter")
+}
+
+/// Generate the subject on which a given provider forwards its log records
+///
+/// Providers that opt into log forwarding publish JSON-encoded records on this subject, so that
+/// their logs can be followed from anywhere in the lattice. `provider_key` may be `*` to follow
+/// all providers.
+#[must_use]
+pub fn provider_log_subject(lattice: &str, provider_key: &str) -> String {
+    format!("wasmbus.provider_log.{lattice}.{provider_key}")
+}
+
 /// Generate the wasmbus RPC subject for retrieving health information for a given provider
 ///
 /// When messages are published on this subject, hosts trigger health checks on providers (i.e. a [`HealthCheckRequest`])
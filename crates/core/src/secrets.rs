@@ -0,0 +1,60 @@
+//! Core reusable types for handling secret values, such as passwords and tokens
+
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+/// A secret value, such as a password or token, kept apart from plain configuration.
+///
+/// The contents are redacted when formatted with [`Debug`](fmt::Debug) or
+/// [`Display`](fmt::Display), and are zeroed from memory when the value is dropped.
+#[derive(Clone, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretValue {
+    /// A UTF-8 secret, e.g. a password or API token
+    String(String),
+    /// A binary secret, e.g. a private key
+    Bytes(#[serde(with = "serde_bytes")] Vec<u8>),
+}
+
+impl SecretValue {
+    /// Returns the secret as a string, or `None` if it is a binary secret
+    #[must_use]
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            SecretValue::String(s) => Some(s),
+            SecretValue::Bytes(_) => None,
+        }
+    }
+
+    /// Returns the raw bytes of the secret, regardless of how it was delivered
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            SecretValue::String(s) => s.as_bytes(),
+            SecretValue::Bytes(b) => b,
+        }
+    }
+}
+
+impl fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretValue(redacted)")
+    }
+}
+
+impl fmt::Display for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("redacted")
+    }
+}
+
+impl Drop for SecretValue {
+    fn drop(&mut self) {
+        match self {
+            SecretValue::String(s) => s.zeroize(),
+            SecretValue::Bytes(b) => b.zeroize(),
+        }
+    }
+}
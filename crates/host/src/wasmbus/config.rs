@@ -166,6 +166,13 @@ impl ConfigBundle {
         self.merged_config.read().await
     }
 
+    /// Like [`ConfigBundle::changed`], but returns `None` instead of the current config once the
+    /// bundle can no longer change, e.g. because it has no named config to watch
+    pub async fn try_changed(&mut self) -> Option<RwLockReadGuard<'_, HashMap<String, String>>> {
+        self.changed_receiver.changed().await.ok()?;
+        Some(self.merged_config.read().await)
+    }
+
     /// Returns a reference to the ordered list of config names handled by this bundle
     #[must_use]
     pub fn config_names(&self) -> &Vec<String> {
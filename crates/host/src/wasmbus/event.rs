@@ -10,6 +10,7 @@ use tracing::instrument;
 use ulid::Ulid;
 use uuid::Uuid;
 use wascap::jwt;
+use wasmcloud_core::HealthCheckResponse;
 
 fn format_component_claims(claims: &jwt::Claims<jwt::Component>) -> serde_json::Value {
     let issuer = &claims.issuer;
@@ -196,10 +197,15 @@ pub fn provider_stopped(
 pub fn provider_health_check(
     host_id: impl AsRef<str>,
     provider_id: impl AsRef<str>,
+    HealthCheckResponse {
+        message, details, ..
+    }: &HealthCheckResponse,
 ) -> serde_json::Value {
     json!({
         "host_id": host_id.as_ref(),
         "provider_id": provider_id.as_ref(),
+        "message": message,
+        "details": details,
     })
 }
 
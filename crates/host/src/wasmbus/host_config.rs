@@ -1,6 +1,7 @@
 use crate::OciConfig;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -24,6 +25,9 @@ pub struct Host {
     pub ctl_topic_prefix: String,
     /// NATS URL to connect to for component RPC
     pub rpc_nats_url: Url,
+    /// Further NATS URLs to fail over to for component RPC, e.g. other leaf node seeds. These are
+    /// used by the host and passed on to providers
+    pub rpc_nats_failover_urls: Vec<Url>,
     /// Timeout period for all RPC calls
     pub rpc_timeout: Duration,
     /// Authentication JWT for RPC connection, must be specified with rpc_seed
@@ -32,6 +36,17 @@ pub struct Host {
     pub rpc_key: Option<Arc<KeyPair>>,
     /// Whether to require TLS for RPC connection
     pub rpc_tls: bool,
+    /// Path to a NATS credentials file to authenticate the RPC connection with, takes precedence
+    /// over rpc_jwt and rpc_key
+    pub rpc_credsfile: Option<PathBuf>,
+    /// Paths to PEM-encoded root certificates to trust for the RPC connection, in addition to the
+    /// system roots
+    pub rpc_tls_ca_files: Vec<PathBuf>,
+    /// Path to a PEM-encoded client certificate to authenticate the RPC connection with mutual
+    /// TLS, must be specified with rpc_tls_client_key
+    pub rpc_tls_client_cert: Option<PathBuf>,
+    /// Path to the PEM-encoded private key of rpc_tls_client_cert
+    pub rpc_tls_client_key: Option<PathBuf>,
     /// The lattice the host belongs to
     pub lattice: String,
     /// The domain to use for host Jetstream operations
@@ -42,6 +57,12 @@ pub struct Host {
     pub host_key: Option<Arc<KeyPair>>,
     /// The amount of time to wait for a provider to gracefully shut down before terminating it
     pub provider_shutdown_delay: Option<Duration>,
+    /// Maximum number of attempts providers make to reconnect their RPC connection after it was
+    /// lost, the NATS client default is used if unset
+    pub provider_rpc_max_reconnects: Option<usize>,
+    /// Upper bound of the backoff between attempts of providers to reconnect their RPC
+    /// connection, the NATS client default is used if unset
+    pub provider_rpc_reconnect_delay_max: Option<Duration>,
     /// Configuration for downloading artifacts from OCI registries
     pub oci_opts: OciConfig,
     /// Whether to allow loading component or provider components from the filesystem
@@ -61,6 +82,13 @@ pub struct Host {
     pub version: String,
 }
 
+impl Host {
+    /// All NATS URLs for component RPC, starting with `rpc_nats_url`
+    pub fn rpc_nats_urls(&self) -> impl Iterator<Item = &Url> {
+        std::iter::once(&self.rpc_nats_url).chain(&self.rpc_nats_failover_urls)
+    }
+}
+
 /// Configuration for wasmCloud policy service
 #[derive(Clone, Debug, Default)]
 pub struct PolicyService {
@@ -83,15 +111,22 @@ impl Default for Host {
             ctl_topic_prefix: "wasmbus.ctl".to_string(),
             rpc_nats_url: Url::parse("nats://localhost:4222")
                 .expect("failed to parse RPC NATS URL"),
+            rpc_nats_failover_urls: Vec::default(),
             rpc_timeout: Duration::from_millis(2000),
             rpc_jwt: None,
             rpc_key: None,
             rpc_tls: false,
+            rpc_credsfile: None,
+            rpc_tls_ca_files: Vec::default(),
+            rpc_tls_client_cert: None,
+            rpc_tls_client_key: None,
             lattice: "default".to_string(),
             js_domain: None,
             labels: HashMap::default(),
             host_key: None,
             provider_shutdown_delay: None,
+            provider_rpc_max_reconnects: None,
+            provider_rpc_reconnect_delay_max: None,
             oci_opts: OciConfig::default(),
             allow_file_load: false,
             enable_structured_logging: false,
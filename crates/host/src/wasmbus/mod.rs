@@ -12,8 +12,10 @@ use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::env::consts::{ARCH, FAMILY, OS};
 use std::future::Future;
+use std::mem;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::process::Stdio;
 use std::str::FromStr;
@@ -27,7 +29,7 @@ use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
 use bytes::{BufMut, Bytes, BytesMut};
 use cloudevents::{EventBuilder, EventBuilderV10};
-use futures::future::Either;
+use futures::future::{join_all, Either};
 use futures::stream::{select_all, AbortHandle, Abortable, SelectAll};
 use futures::{join, stream, try_join, Stream, StreamExt, TryFutureExt, TryStreamExt};
 use nkeys::{KeyPair, KeyPairType};
@@ -40,6 +42,7 @@ use tokio::time::{interval_at, timeout, timeout_at, Instant};
 use tokio::{process, select, spawn};
 use tokio_stream::wrappers::IntervalStream;
 use tracing::{debug, error, info, instrument, trace, warn};
+use url::Url;
 use uuid::Uuid;
 use wascap::{jwt, prelude::ClaimsBuilder};
 use wasmcloud_control_interface::{
@@ -49,7 +52,10 @@ use wasmcloud_control_interface::{
     ScaleComponentCommand, StartProviderCommand, StopHostCommand, StopProviderCommand,
     UpdateComponentCommand,
 };
-use wasmcloud_core::{ComponentId, HealthCheckResponse, HostData, OtelConfig, CTL_API_VERSION_1};
+use wasmcloud_core::{
+    ComponentId, HealthCheckResponse, HostData, LinkPutResponse, OtelConfig, ShutdownReason,
+    CTL_API_VERSION_1,
+};
 use wasmcloud_runtime::capability::{messaging, IncomingHttp as _, MessagingHandler as _};
 use wasmcloud_runtime::Runtime;
 use wasmcloud_tracing::context::TraceContextInjector;
@@ -456,14 +462,14 @@ pub struct Host {
     stop_rx: watch::Receiver<Option<Instant>>,
     queue: AbortHandle,
     // Component ID -> All Links
-    links: RwLock<HashMap<String, Vec<InterfaceLinkDefinition>>>,
+    links: Arc<RwLock<HashMap<String, Vec<InterfaceLinkDefinition>>>>,
     component_claims: Arc<RwLock<HashMap<ComponentId, jwt::Claims<jwt::Component>>>>, // TODO: use a single map once Claims is an enum
     provider_claims: Arc<RwLock<HashMap<String, jwt::Claims<jwt::CapabilityProvider>>>>,
     metrics: Arc<HostMetrics>,
     max_execution_time: Duration,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
 /// The specification of a component that is or did run in the lattice. This contains all of the information necessary to
 /// instantiate a component in the lattice (url and digest) as well as configuration and links in order to facilitate
 /// runtime execution of the component. Each `import` in a component's WIT world will need a corresponding link for the
@@ -592,23 +598,48 @@ async fn create_bucket(
 ///
 /// Returns an error if:
 /// - Only one of JWT or seed is specified, as we cannot authenticate with only one of them
+/// - Only one of client certificate or key is specified
+/// - The credentials file cannot be read
 /// - Connection fails
+#[allow(clippy::too_many_arguments)]
 async fn connect_nats(
     addr: impl async_nats::ToServerAddrs,
     jwt: Option<&String>,
     key: Option<Arc<KeyPair>>,
+    credsfile: Option<&Path>,
     require_tls: bool,
+    ca_files: &[PathBuf],
+    client_cert: Option<&Path>,
+    client_key: Option<&Path>,
     request_timeout: Option<Duration>,
 ) -> anyhow::Result<async_nats::Client> {
-    let opts = async_nats::ConnectOptions::new().require_tls(require_tls);
-    let opts = match (jwt, key) {
-        (Some(jwt), Some(key)) => opts.jwt(jwt.to_string(), {
+    let opts = ca_files.iter().fold(
+        async_nats::ConnectOptions::new().require_tls(require_tls),
+        |opts, ca_file| opts.add_root_certificates(ca_file.clone()),
+    );
+    let opts = match (client_cert, client_key) {
+        (Some(cert), Some(key)) => {
+            opts.add_client_certificate(cert.to_path_buf(), key.to_path_buf())
+        }
+        (Some(_), None) | (None, Some(_)) => {
+            bail!("cannot authenticate with a client certificate if only one of certificate or key is specified")
+        }
+        (None, None) => opts,
+    };
+    let opts = match (credsfile, jwt, key) {
+        (Some(credsfile), _, _) => opts.credentials_file(credsfile).await.with_context(|| {
+            format!(
+                "failed to read NATS credentials file `{}`",
+                credsfile.display()
+            )
+        })?,
+        (None, Some(jwt), Some(key)) => opts.jwt(jwt.to_string(), {
             move |nonce| {
                 let key = key.clone();
                 async move { key.sign(&nonce).map_err(async_nats::AuthError::new) }
             }
         }),
-        (Some(_), None) | (None, Some(_)) => {
+        (None, Some(_), None) | (None, None, Some(_)) => {
             bail!("cannot authenticate if only one of jwt or seed is specified")
         }
         _ => opts,
@@ -806,7 +837,11 @@ impl Host {
                     config.ctl_nats_url.as_str(),
                     config.ctl_jwt.as_ref(),
                     config.ctl_key.clone(),
+                    None,
                     config.ctl_tls,
+                    &[],
+                    None,
+                    None,
                     None,
                 )
                 .await
@@ -823,15 +858,17 @@ impl Host {
                 Ok((ctl_nats, queue))
             },
             async {
-                debug!(
-                    rpc_nats_url = config.rpc_nats_url.as_str(),
-                    "connecting to NATS RPC server"
-                );
+                let rpc_nats_urls: Vec<_> = config.rpc_nats_urls().map(Url::as_str).collect();
+                debug!(?rpc_nats_urls, "connecting to NATS RPC server");
                 connect_nats(
-                    config.rpc_nats_url.as_str(),
+                    rpc_nats_urls.as_slice(),
                     config.rpc_jwt.as_ref(),
                     config.rpc_key.clone(),
+                    config.rpc_credsfile.as_deref(),
                     config.rpc_tls,
+                    &config.rpc_tls_ca_files,
+                    config.rpc_tls_client_cert.as_deref(),
+                    config.rpc_tls_client_key.as_deref(),
                     Some(config.rpc_timeout),
                 )
                 .await
@@ -932,7 +969,7 @@ impl Host {
             stop_rx,
             stop_tx,
             queue: queue_abort.clone(),
-            links: RwLock::default(),
+            links: Arc::default(),
             component_claims: Arc::default(),
             provider_claims: Arc::default(),
             metrics: Arc::new(metrics),
@@ -1071,6 +1108,31 @@ impl Host {
             data_watch_abort.abort();
             host.policy_manager.policy_changes.abort();
             let _ = try_join!(queue, data_watch, heartbeat).context("failed to await tasks")?;
+            // Give providers the chance to shut down gracefully before they are killed
+            let host_id = host.host_key.public_key();
+            let providers: Vec<_> = host.providers.write().await.drain().collect();
+            join_all(
+                providers
+                    .into_iter()
+                    .map(|(provider_id, Provider { child, .. })| {
+                        let host = &host;
+                        let host_id = &host_id;
+                        async move {
+                            if let Err(e) = host
+                                .request_provider_shutdown(
+                                    &provider_id,
+                                    host_id,
+                                    ShutdownReason::HostShutdown,
+                                )
+                                .await
+                            {
+                                warn!(?e, %provider_id, "failed to request provider shutdown");
+                            }
+                            child.abort();
+                        }
+                    }),
+            )
+            .await;
             host.publish_event(
                 "host_stopped",
                 json!({
@@ -2056,7 +2118,6 @@ impl Host {
             .generate(config)
             .await
             .context("Unable to fetch requested config")?;
-        // TODO(#1648): Implement redelivery of changed configuration when `config.changed()` is true
 
         let host_id = host_id.to_string();
         spawn(async move {
@@ -2143,6 +2204,18 @@ impl Host {
                 .map(|key| key.seed())
                 .transpose()
                 .context("private key missing for provider RPC key")?;
+            let lattice_rpc_reconnect_delay_max_ms = self
+                .host_config
+                .provider_rpc_reconnect_delay_max
+                .map(|delay| delay.as_millis().try_into())
+                .transpose()
+                .context("failed to convert provider_rpc_reconnect_delay_max to u64")?;
+            let provider_shutdown_delay_ms = self
+                .host_config
+                .provider_shutdown_delay
+                .map(|delay| delay.as_millis().try_into())
+                .transpose()
+                .context("failed to convert provider_shutdown_delay to u64")?;
             let default_rpc_timeout_ms = Some(
                 self.host_config
                     .rpc_timeout
@@ -2162,30 +2235,13 @@ impl Host {
             };
             let config_generator = self.config_generator.clone();
 
-            // Prepare startup links by generating the source and target configs. Note that because the provider may be the source
-            // or target of a link, we need to iterate over all links to find the ones that involve the provider.
-            let link_definitions = stream::iter(self.links.read().await.values().flatten())
-                .filter_map(|link| async {
-                    if link.source_id == provider_id || link.target == provider_id {
-                        if let Ok(provider_link) =
-                            resolve_link_config(&config_generator, link.clone()).await
-                        {
-                            Some(provider_link)
-                        } else {
-                            error!(
-                                provider_id,
-                                source_id = link.source_id,
-                                target = link.target,
-                                "failed to resolve link config, skipping link"
-                            );
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<wasmcloud_core::InterfaceLinkDefinition>>()
-                .await;
+            // Prepare startup links by generating the source and target configs
+            let link_definitions = provider_link_definitions(
+                &config_generator,
+                &*self.links.read().await,
+                provider_id,
+            )
+            .await;
 
             let host_data = HostData {
                 host_id: self.host_key.public_key(),
@@ -2193,7 +2249,14 @@ impl Host {
                 link_name: "default".to_string(),
                 lattice_rpc_user_jwt: self.host_config.rpc_jwt.clone().unwrap_or_default(),
                 lattice_rpc_user_seed: lattice_rpc_user_seed.unwrap_or_default(),
-                lattice_rpc_url: self.host_config.rpc_nats_url.to_string(),
+                lattice_rpc_url: lattice_rpc_url(&self.host_config),
+                lattice_rpc_credsfile: self.host_config.rpc_credsfile.clone(),
+                lattice_rpc_tls: self.host_config.rpc_tls,
+                lattice_rpc_tls_ca_files: self.host_config.rpc_tls_ca_files.clone(),
+                lattice_rpc_tls_client_cert: self.host_config.rpc_tls_client_cert.clone(),
+                lattice_rpc_tls_client_key: self.host_config.rpc_tls_client_key.clone(),
+                lattice_rpc_max_reconnects: self.host_config.provider_rpc_max_reconnects,
+                lattice_rpc_reconnect_delay_max_ms,
                 env_values: vec![],
                 instance_id: Uuid::new_v4().to_string(),
                 provider_key: provider_id.to_string(),
@@ -2201,6 +2264,7 @@ impl Host {
                 config: config.get_config().await.clone(),
                 cluster_issuers: vec![],
                 default_rpc_timeout_ms,
+                provider_shutdown_delay_ms,
                 log_level: Some(self.host_config.log_level.clone()),
                 structured_logging: self.host_config.enable_structured_logging,
                 otel_config,
@@ -2229,6 +2293,13 @@ impl Host {
                 let _ = child_cmd.env("RUST_LOG", rust_log);
             }
 
+            // Proxy OpenTelemetry SDK settings (e.g. exporter headers and trace sampling), so that
+            // providers export telemetry the same way the host does. Endpoints are passed in the
+            // host data, which takes precedence
+            for (key, value) in env::vars().filter(|(key, _)| key.starts_with("OTEL_")) {
+                let _ = child_cmd.env(key, value);
+            }
+
             let mut child = child_cmd
                 .stdin(Stdio::piped())
                 .kill_on_drop(true)
@@ -2253,6 +2324,32 @@ impl Host {
             let health_lattice = self.host_config.lattice.clone();
             let health_host_id = host_id.to_string();
             let health_provider_id = provider_id.to_string();
+            let config_update_topic = wasmcloud_core::rpc::provider_config_update_subject(
+                &self.host_config.lattice,
+                provider_id,
+            );
+            let links = Arc::clone(&self.links);
+            let mut link_get = rpc_nats
+                .subscribe(wasmcloud_core::rpc::link_get_subject(
+                    &self.host_config.lattice,
+                    provider_id,
+                ))
+                .await
+                .context("failed to subscribe to provider link requests")?;
+            let config_data = self.config_data.clone();
+            let mut config_get = rpc_nats
+                .subscribe(wasmcloud_core::rpc::named_config_get_subject(
+                    &self.host_config.lattice,
+                    provider_id,
+                ))
+                .await
+                .context("failed to subscribe to provider config requests")?;
+            // The bundle starts out marked as changed, so acknowledge the config that was already
+            // delivered in the host data and only redeliver subsequent changes. A bundle without
+            // named config never changes, so there is nothing to watch
+            let mut config = config;
+            drop(config.try_changed().await);
+            let mut watch_config = !config.config_names().is_empty();
             let child = spawn(async move {
                 // Check the health of the provider every 30 seconds
                 let mut health_check = tokio::time::interval(Duration::from_secs(30));
@@ -2274,7 +2371,7 @@ impl Host {
                                 request,
                                 ).await {
                                     match (serde_json::from_slice::<HealthCheckResponse>(&payload), previous_healthy) {
-                                        (Ok(HealthCheckResponse { healthy: true, ..}), false) => {
+                                        (Ok(res @ HealthCheckResponse { healthy: true, ..}), false) => {
                                             trace!(provider_id=health_provider_id, "provider health check succeeded");
                                             previous_healthy = true;
                                             if let Err(e) = event::publish(
@@ -2285,6 +2382,7 @@ impl Host {
                                                 event::provider_health_check(
                                                     &health_host_id,
                                                     &health_provider_id,
+                                                    &res,
                                                 )
                                             ).await {
                                                 warn!(
@@ -2294,7 +2392,7 @@ impl Host {
                                                 );
                                             }
                                         },
-                                        (Ok(HealthCheckResponse { healthy: false, ..}), true) => {
+                                        (Ok(res @ HealthCheckResponse { healthy: false, ..}), true) => {
                                             trace!(provider_id=health_provider_id, "provider health check failed");
                                             previous_healthy = false;
                                             if let Err(e) = event::publish(
@@ -2305,6 +2403,7 @@ impl Host {
                                                 event::provider_health_check(
                                                     &health_host_id,
                                                     &health_provider_id,
+                                                    &res,
                                                 )
                                             ).await {
                                                 warn!(
@@ -2315,7 +2414,7 @@ impl Host {
                                             }
                                         }
                                         // If the provider health status didn't change, we simply publish a health check status event
-                                        (Ok(_), _) => {
+                                        (Ok(res), _) => {
                                             if let Err(e) = event::publish(
                                                 &event_builder,
                                                 &ctl_nats,
@@ -2324,6 +2423,7 @@ impl Host {
                                                 event::provider_health_check(
                                                     &health_host_id,
                                                     &health_provider_id,
+                                                    &res,
                                                 )
                                             ).await {
                                                 warn!(
@@ -2343,6 +2443,67 @@ impl Host {
                                     warn!(provider_id = health_provider_id, "failed to request provider health, retrying in 30 seconds");
                                 }
                         }
+                        // Redeliver the merged config to the provider whenever any of its named config changes
+                        update = config.try_changed(), if watch_config => {
+                            let Some(update) = update else {
+                                warn!(provider_id = health_provider_id, "provider config bundle closed, no longer redelivering config");
+                                watch_config = false;
+                                continue;
+                            };
+                            trace!(provider_id=health_provider_id, "provider config changed, redelivering");
+                            let payload = serde_json::to_vec(&*update);
+                            drop(update);
+                            match payload {
+                                Ok(payload) => {
+                                    if let Err(e) = rpc_nats.publish(config_update_topic.clone(), payload.into()).await {
+                                        warn!(provider_id = health_provider_id, error = %e, "failed to publish provider config update");
+                                    }
+                                }
+                                Err(e) => warn!(provider_id = health_provider_id, error = %e, "failed to serialize provider config update"),
+                            }
+                        }
+                        // Reply with the current links of the provider, so that it can resynchronize
+                        // link state it missed, e.g. while disconnected from the lattice
+                        Some(msg) = link_get.next() => {
+                            let Some(reply) = msg.reply else {
+                                continue;
+                            };
+                            let link_definitions = provider_link_definitions(
+                                &config_generator,
+                                &*links.read().await,
+                                &health_provider_id,
+                            ).await;
+                            match serde_json::to_vec(&link_definitions) {
+                                Ok(payload) => {
+                                    if let Err(e) = rpc_nats.publish(reply, payload.into()).await {
+                                        warn!(provider_id = health_provider_id, error = %e, "failed to publish provider links");
+                                    }
+                                }
+                                Err(e) => warn!(provider_id = health_provider_id, error = %e, "failed to serialize provider links"),
+                            }
+                        }
+                        // Serve named config to the provider over the RPC connection, since the
+                        // config bucket may only be reachable through the control interface connection
+                        Some(msg) = config_get.next() => {
+                            let Some(reply) = msg.reply else {
+                                continue;
+                            };
+                            let Ok(name) = std::str::from_utf8(&msg.payload) else {
+                                warn!(provider_id = health_provider_id, "ignoring config request with invalid name");
+                                continue;
+                            };
+                            let payload = match config_data.get(name).await {
+                                Ok(Some(config)) => config,
+                                Ok(None) => Bytes::from_static(b"null"),
+                                Err(e) => {
+                                    warn!(provider_id = health_provider_id, error = %e, name, "failed to fetch named config");
+                                    continue;
+                                }
+                            };
+                            if let Err(e) = rpc_nats.publish(reply, payload).await {
+                                warn!(provider_id = health_provider_id, error = %e, "failed to publish named config");
+                            }
+                        }
                         exit_status = child.wait() => match exit_status {
                             Ok(status) => {
                                 debug!("`{}` exited with `{status:?}`", path.display());
@@ -2403,8 +2564,28 @@ impl Host {
             child, annotations, ..
         } = entry.remove();
 
-        // Send a request to the provider, requesting a graceful shutdown
-        let req = serde_json::to_vec(&json!({ "host_id": host_id }))
+        self.request_provider_shutdown(&provider_id, host_id, ShutdownReason::Stop)
+            .await?;
+        child.abort();
+        info!(provider_id, "provider stopped");
+        self.publish_event(
+            "provider_stopped",
+            event::provider_stopped(&annotations, host_id, provider_id, "stop"),
+        )
+        .await?;
+        Ok(CtlResponse::success())
+    }
+
+    /// Send a request to the provider, requesting a graceful shutdown, and wait for it to
+    /// acknowledge the request for at most the provider shutdown delay
+    #[instrument(level = "debug", skip(self))]
+    async fn request_provider_shutdown(
+        &self,
+        provider_id: &str,
+        host_id: &str,
+        reason: ShutdownReason,
+    ) -> anyhow::Result<()> {
+        let req = serde_json::to_vec(&json!({ "host_id": host_id, "reason": reason }))
             .context("failed to encode provider stop request")?;
         let req = async_nats::Request::new()
             .payload(req.into())
@@ -2429,14 +2610,7 @@ impl Host {
                 "provider did not gracefully shut down in time, shutting down forcefully"
             );
         }
-        child.abort();
-        info!(provider_id, "provider stopped");
-        self.publish_event(
-            "provider_stopped",
-            event::provider_stopped(&annotations, host_id, provider_id, "stop"),
-        )
-        .await?;
-        Ok(CtlResponse::success())
+        Ok(())
     }
 
     #[instrument(level = "debug", skip_all)]
@@ -2596,20 +2770,37 @@ impl Host {
 
         // If we can find an existing link with the same source, target, namespace, package, and name, update it.
         // Otherwise, add the new link to the component specification.
-        if let Some(existing_link_index) = component_spec.links.iter().position(|link| {
-            link.source_id == source_id
-                && link.target == target
-                && link.wit_namespace == wit_namespace
-                && link.wit_package == wit_package
-                && link.name == name
-        }) {
-            if let Some(existing_link) = component_spec.links.get_mut(existing_link_index) {
-                *existing_link = interface_link_definition.clone();
-            }
+        let previous_link = if let Some(existing_link_index) =
+            component_spec.links.iter().position(|link| {
+                link.source_id == source_id
+                    && link.target == target
+                    && link.wit_namespace == wit_namespace
+                    && link.wit_package == wit_package
+                    && link.name == name
+            }) {
+            component_spec
+                .links
+                .get_mut(existing_link_index)
+                .map(|existing_link| mem::replace(existing_link, interface_link_definition.clone()))
         } else {
             component_spec.links.push(interface_link_definition.clone());
+            None
         };
 
+        // Check with the providers on both ends before storing the link, so that a rejected link
+        // never reaches the rest of the lattice
+        if let Some(message) = self
+            .put_provider_link(&source_id, &target, provider_link)
+            .await?
+        {
+            warn!(source_id, target, message, "provider rejected link");
+            self.revert_provider_link(&interface_link_definition, previous_link)
+                .await;
+            return Ok(CtlResponse::error(&format!(
+                "provider rejected link: {message}"
+            )));
+        }
+
         // Update component specification with the new link
         self.store_component_spec(&source_id, &component_spec)
             .await?;
@@ -2617,9 +2808,6 @@ impl Host {
         let set_event = event::linkdef_set(&interface_link_definition);
         self.publish_event("linkdef_set", set_event).await?;
 
-        self.put_provider_link(&source_id, &target, provider_link)
-            .await?;
-
         Ok(CtlResponse::success())
     }
 
@@ -2652,7 +2840,7 @@ impl Host {
 
         // If we can find an existing link with the same source, namespace, package, and name, remove it
         // and update the component specification.
-        let deleted_link_target = if let Some(existing_link_index) =
+        let deleted_link = if let Some(existing_link_index) =
             component_spec.links.iter().position(|link| {
                 link.source_id == source_id
                     && link.wit_namespace == wit_namespace
@@ -2661,7 +2849,7 @@ impl Host {
             }) {
             // Sanity safety check since `swap_remove` will panic if the index is out of bounds
             if existing_link_index < component_spec.links.len() {
-                Some(component_spec.links.swap_remove(existing_link_index).target)
+                Some(component_spec.links.swap_remove(existing_link_index))
             } else {
                 None
             }
@@ -2679,8 +2867,20 @@ impl Host {
         )
         .await?;
 
-        self.del_provider_link(&source_id, deleted_link_target, payload.to_owned().into())
+        // Send the full deleted link to providers, so that a provider with several links to the same
+        // component can tell which one was removed
+        if let Some(link) = deleted_link {
+            self.del_provider_link(wasmcloud_core::InterfaceLinkDefinition {
+                source_id: link.source_id,
+                target: link.target,
+                name: link.name,
+                wit_namespace: link.wit_namespace,
+                wit_package: link.wit_package,
+                interfaces: link.interfaces,
+                ..Default::default()
+            })
             .await?;
+        }
 
         Ok(CtlResponse::success())
     }
@@ -2727,13 +2927,14 @@ impl Host {
         serde_json::from_slice::<HashMap<String, String>>(&data)
             .context("config data should be a map of string -> string")?;
         self.config_data
-            .put(config_name, data)
+            .put(config_name, data.clone())
             .await
             .context("unable to store config data")?;
         // We don't write it into the cached data and instead let the caching thread handle it as we
         // won't need it immediately.
         self.publish_event("config_set", event::config_set(config_name))
             .await?;
+        self.publish_named_config_changed(config_name, data).await;
 
         Ok(CtlResponse::success())
     }
@@ -2749,10 +2950,29 @@ impl Host {
 
         self.publish_event("config_deleted", event::config_deleted(config_name))
             .await?;
+        self.publish_named_config_changed(config_name, Bytes::from_static(b"null"))
+            .await;
 
         Ok(CtlResponse::success())
     }
 
+    /// Notify providers watching the named config `config_name` of its new value, JSON-encoded
+    async fn publish_named_config_changed(&self, config_name: &str, payload: Bytes) {
+        if let Err(err) = self
+            .rpc_nats
+            .publish(
+                wasmcloud_core::rpc::named_config_changed_subject(
+                    &self.host_config.lattice,
+                    config_name,
+                ),
+                payload,
+            )
+            .await
+        {
+            warn!(%err, config_name, "failed to publish named config change");
+        }
+    }
+
     #[instrument(level = "debug", skip_all)]
     async fn handle_ping_hosts(
         &self,
@@ -2954,42 +3174,118 @@ impl Host {
         }
     }
 
-    /// Publishes a link to the lattice for all instances of a provider to handle
-    /// Right now this is publishing _both_ to the source and the target in order to
+    /// Sends a link to the lattice for a provider to handle
+    /// Right now this is sending the link to _both_ the source and the target in order to
     /// ensure that the provider is aware of the link. This would cause problems if a provider
     /// is linked to a provider (which it should never be.)
+    ///
+    /// Returns the reason a provider rejected the link, if one did
     #[instrument(level = "debug", skip(self, provider_link))]
     async fn put_provider_link(
         &self,
         source_id: &str,
         target: &str,
         provider_link: wasmcloud_core::InterfaceLinkDefinition,
-    ) -> anyhow::Result<()> {
-        let lattice = &self.host_config.lattice;
+    ) -> anyhow::Result<Option<String>> {
         let payload: Bytes = serde_json::to_vec(&provider_link)
             .context("failed to serialize provider link definition")?
             .into();
-        let source_provider = self
+        let (source_rejection, target_rejection) = try_join!(
+            self.request_provider_link_put(source_id, payload.clone()),
+            self.request_provider_link_put(target, payload),
+        )?;
+        Ok(source_rejection.or(target_rejection))
+    }
+
+    /// Sends a link to all instances of the provider `provider_id`, if any are listening, and
+    /// collects their replies until the RPC timeout elapses. Returns the reason an instance
+    /// rejected the link, if one did
+    async fn request_provider_link_put(
+        &self,
+        provider_id: &str,
+        payload: Bytes,
+    ) -> anyhow::Result<Option<String>> {
+        let lattice = &self.host_config.lattice;
+        let reply = self.rpc_nats.new_inbox();
+        let mut replies = self
             .rpc_nats
-            .publish_with_headers(
-                format!("wasmbus.rpc.{lattice}.{source_id}.linkdefs.put"),
-                injector_to_headers(&TraceContextInjector::default_with_span()),
-                payload.clone(),
-            )
+            .subscribe(reply.clone())
             .await
-            .context("failed to publish provider link definition put");
-        let target_provider = self
-            .rpc_nats
-            .publish_with_headers(
-                format!("wasmbus.rpc.{lattice}.{target}.linkdefs.put"),
+            .context("failed to subscribe to provider link put replies")?;
+        self.rpc_nats
+            .publish_with_reply_and_headers(
+                format!("wasmbus.rpc.{lattice}.{provider_id}.linkdefs.put"),
+                reply,
                 injector_to_headers(&TraceContextInjector::default_with_span()),
                 payload,
             )
             .await
-            .context("failed to publish provider link definition put");
-        source_provider?;
-        target_provider?;
-        Ok(())
+            .context("failed to send provider link definition put")?;
+        // Any number of instances of the provider may be running across the lattice, so there is
+        // no telling when the last one has replied. Providers built with older SDKs receive the
+        // link without replying at all
+        let deadline = Instant::now() + self.host_config.rpc_timeout;
+        while let Ok(Some(msg)) = timeout_at(deadline, replies.next()).await {
+            // Components do not receive links over NATS, so only providers respond
+            if msg.status == Some(async_nats::StatusCode::NO_RESPONDERS) {
+                break;
+            }
+            match serde_json::from_slice::<LinkPutResponse>(&msg.payload) {
+                Ok(LinkPutResponse {
+                    accepted: false,
+                    message,
+                }) => {
+                    return Ok(Some(
+                        message.unwrap_or_else(|| "no reason given".to_string()),
+                    ))
+                }
+                Ok(LinkPutResponse { accepted: true, .. }) => {}
+                Err(e) => {
+                    warn!(
+                        ?e,
+                        provider_id, "failed to decode provider link put response"
+                    );
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Undoes a rejected link put on the provider instances that already accepted `link`, by
+    /// sending them the `previous_link` it replaced, or deleting it if it was new
+    async fn revert_provider_link(
+        &self,
+        link: &wasmcloud_control_interface::InterfaceLinkDefinition,
+        previous_link: Option<wasmcloud_control_interface::InterfaceLinkDefinition>,
+    ) {
+        let res = if let Some(previous_link) = previous_link {
+            match resolve_link_config(&self.config_generator, previous_link).await {
+                Ok(provider_link) => self
+                    .put_provider_link(&link.source_id, &link.target, provider_link)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            }
+        } else {
+            self.del_provider_link(wasmcloud_core::InterfaceLinkDefinition {
+                source_id: link.source_id.clone(),
+                target: link.target.clone(),
+                name: link.name.clone(),
+                wit_namespace: link.wit_namespace.clone(),
+                wit_package: link.wit_package.clone(),
+                interfaces: link.interfaces.clone(),
+                ..Default::default()
+            })
+            .await
+        };
+        if let Err(e) = res {
+            warn!(
+                ?e,
+                source_id = link.source_id,
+                target = link.target,
+                "failed to revert rejected link on providers"
+            );
+        }
     }
 
     /// Publishes a delete link to the lattice for all instances of a provider to handle
@@ -2997,15 +3293,19 @@ impl Host {
     /// ensure that the provider is aware of the link delete. This would cause problems if a provider
     /// is linked to a provider (which it should never be.)
     ///
-    /// TODO: Instead of delivering the named config, deliver the actual source and target config to the provider
-    #[instrument(level = "debug", skip(self, payload))]
+    /// The deleted link is sent without its source and target config, which providers have no use
+    /// for once the link is gone
+    #[instrument(level = "debug", skip(self, provider_link))]
     async fn del_provider_link(
         &self,
-        source_id: &str,
-        target: Option<String>,
-        payload: Bytes,
+        provider_link: wasmcloud_core::InterfaceLinkDefinition,
     ) -> anyhow::Result<()> {
         let lattice = &self.host_config.lattice;
+        let source_id = &provider_link.source_id;
+        let target = &provider_link.target;
+        let payload: Bytes = serde_json::to_vec(&provider_link)
+            .context("failed to serialize provider link definition")?
+            .into();
         let source_provider = self
             .rpc_nats
             .publish_with_headers(
@@ -3015,18 +3315,17 @@ impl Host {
             )
             .await
             .context("failed to publish provider link definition del");
-        if let Some(target) = target {
-            self.rpc_nats
-                .publish_with_headers(
-                    format!("wasmbus.rpc.{lattice}.{target}.linkdefs.del"),
-                    injector_to_headers(&TraceContextInjector::default_with_span()),
-                    payload,
-                )
-                .await
-                .context("failed to publish provider link definition del")?;
-        }
-
+        let target_provider = self
+            .rpc_nats
+            .publish_with_headers(
+                format!("wasmbus.rpc.{lattice}.{target}.linkdefs.del"),
+                injector_to_headers(&TraceContextInjector::default_with_span()),
+                payload,
+            )
+            .await
+            .context("failed to publish provider link definition del");
         source_provider?;
+        target_provider?;
         Ok(())
     }
 
@@ -3266,6 +3565,45 @@ async fn resolve_link_config(
     })
 }
 
+/// NATS URLs for the RPC connection of providers, as a comma-separated list to fail over between
+fn lattice_rpc_url(config: &HostConfig) -> String {
+    config
+        .rpc_nats_urls()
+        .map(Url::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Collect the links that a provider is the source or target of, with their source and target
+/// config resolved. Links whose config cannot be resolved are skipped
+async fn provider_link_definitions(
+    config_generator: &BundleGenerator,
+    links: &HashMap<String, Vec<InterfaceLinkDefinition>>,
+    provider_id: &str,
+) -> Vec<wasmcloud_core::InterfaceLinkDefinition> {
+    stream::iter(links.values().flatten())
+        .filter_map(|link| async {
+            if link.source_id == provider_id || link.target == provider_id {
+                if let Ok(provider_link) = resolve_link_config(config_generator, link.clone()).await
+                {
+                    Some(provider_link)
+                } else {
+                    error!(
+                        provider_id,
+                        source_id = link.source_id,
+                        target = link.target,
+                        "failed to resolve link config, skipping link"
+                    );
+                    None
+                }
+            } else {
+                None
+            }
+        })
+        .collect()
+        .await
+}
+
 /// Helper function to transform a Vec of [`InterfaceLinkDefinition`]s into the structure components expect to be able
 /// to quickly look up the desired target for a given interface
 ///
@@ -3571,6 +3909,28 @@ fn injector_to_headers(injector: &TraceContextInjector) -> async_nats::header::H
 
 #[cfg(test)]
 mod test {
+    #[test]
+    fn lattice_rpc_url_includes_failover_urls() {
+        use super::Url;
+
+        let config = super::HostConfig {
+            rpc_nats_url: Url::parse("nats://leaf-a:4222").unwrap(),
+            rpc_nats_failover_urls: vec![
+                Url::parse("nats://leaf-b:4222").unwrap(),
+                Url::parse("tls://leaf-c:4222").unwrap(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            super::lattice_rpc_url(&config),
+            "nats://leaf-a:4222,nats://leaf-b:4222,tls://leaf-c:4222"
+        );
+        assert_eq!(
+            super::lattice_rpc_url(&super::HostConfig::default()),
+            "nats://localhost:4222"
+        );
+    }
+
     // Ensure that the helper function to translate a list of links into a map of imports works as expected
     #[test]
     fn can_compute_component_links() {
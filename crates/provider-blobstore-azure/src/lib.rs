@@ -8,7 +8,7 @@ use futures::{Stream, StreamExt, TryStreamExt as _};
 use tokio::sync::RwLock;
 use tracing::{error, instrument};
 use wasmcloud_provider_sdk::interfaces::blobstore::Blobstore;
-use wasmcloud_provider_sdk::{Context, LinkConfig, Provider};
+use wasmcloud_provider_sdk::{Context, LinkConfig, LinkDeleteInfo, Provider};
 use wrpc_transport::{AcceptedInvocation, Transmitter};
 
 use config::StorageConfig;
@@ -50,7 +50,10 @@ impl Provider for BlobstoreAzblobProvider {
         Ok(())
     }
 
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         self.config.write().await.remove(source_id);
         Ok(())
     }
@@ -19,7 +19,9 @@ use tokio_stream::wrappers::ReadDirStream;
 use tokio_util::io::ReaderStream;
 use tracing::{debug, error, info, instrument, trace};
 use wasmcloud_provider_sdk::interfaces::blobstore::Blobstore;
-use wasmcloud_provider_sdk::{propagate_trace_for_ctx, Context, LinkConfig, Provider};
+use wasmcloud_provider_sdk::{
+    propagate_trace_for_ctx, Context, LinkConfig, LinkDeleteInfo, Provider,
+};
 use wrpc_transport::{AcceptedInvocation, Transmitter};
 
 #[derive(Default, Debug, Clone)]
@@ -738,7 +740,10 @@ impl Provider for FsProvider {
         Ok(())
     }
 
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         self.config.write().await.remove(source_id);
         Ok(())
     }
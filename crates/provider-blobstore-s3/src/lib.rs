@@ -33,7 +33,9 @@ use tokio_util::io::ReaderStream;
 use tracing::{debug, error, instrument};
 use wasmcloud_provider_sdk::core::tls;
 use wasmcloud_provider_sdk::interfaces::blobstore::Blobstore;
-use wasmcloud_provider_sdk::{propagate_trace_for_ctx, Context, LinkConfig, Provider};
+use wasmcloud_provider_sdk::{
+    propagate_trace_for_ctx, Context, LinkConfig, LinkDeleteInfo, Provider,
+};
 use wrpc_transport::{AcceptedInvocation, Transmitter};
 
 const ALIAS_PREFIX: &str = "alias_";
@@ -1020,7 +1022,10 @@ impl Provider for BlobstoreS3Provider {
     }
 
     /// Handle notification that a link is dropped: close the connection
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         let mut aw = self.actors.write().await;
         aw.remove(source_id);
         Ok(())
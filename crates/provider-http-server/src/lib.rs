@@ -45,7 +45,7 @@ use axum_server::tls_rustls::RustlsConfig;
 use tokio::{spawn, time};
 use tower_http::cors::{self, CorsLayer};
 use tracing::{debug, error, info, instrument, trace};
-use wasmcloud_provider_sdk::{get_connection, LinkConfig, Provider};
+use wasmcloud_provider_sdk::{get_connection, LinkConfig, LinkDeleteInfo, Provider};
 use wrpc_interface_http::IncomingHandler as _;
 
 mod hashmap_ci;
@@ -96,9 +96,12 @@ impl Provider for HttpServerProvider {
     }
 
     /// Handle notification that a link is dropped - stop the http listener
-    async fn delete_link(&self, actor_id: &str) -> anyhow::Result<()> {
-        if let Some((_, server)) = self.actors.remove(actor_id) {
-            info!(%actor_id, "httpserver stopping listener for component");
+    async fn delete_link_as_source(
+        &self,
+        LinkDeleteInfo { target_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
+        if let Some((_, server)) = self.actors.remove(target_id) {
+            info!(%target_id, "httpserver stopping listener for component");
             server.handle.shutdown();
         }
         Ok(())
@@ -21,7 +21,7 @@ use tracing::{debug, error, info, instrument, warn};
 use wasmcloud_provider_sdk::core::HostData;
 use wasmcloud_provider_sdk::{
     get_connection, load_host_data, propagate_trace_for_ctx, run_provider, Context, LinkConfig,
-    Provider,
+    LinkDeleteInfo, Provider,
 };
 
 use exports::wrpc::keyvalue;
@@ -343,7 +343,10 @@ impl Provider for KvRedisProvider {
 
     /// Handle notification that a link is dropped - close the connection
     #[instrument(level = "info", skip(self))]
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         let mut aw = self.sources.write().await;
         if let Some(conn) = aw.remove(source_id) {
             debug!(
@@ -14,7 +14,8 @@ use tokio::task::JoinHandle;
 use tracing::{debug, error, info, instrument, warn};
 use vaultrs::client::{Client as _, VaultClient, VaultClientSettings};
 use wasmcloud_provider_sdk::{
-    get_connection, propagate_trace_for_ctx, run_provider, Context, LinkConfig, Provider,
+    get_connection, propagate_trace_for_ctx, run_provider, Context, LinkConfig, LinkDeleteInfo,
+    Provider,
 };
 
 use crate::config::Config;
@@ -436,7 +437,10 @@ impl Provider for KvVaultProvider {
 
     /// Handle notification that a link is dropped - close the connection
     #[instrument(level = "debug", skip(self))]
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         let mut aw = self.components.write().await;
         if let Some(client) = aw.remove(source_id) {
             debug!("deleting link for component [{source_id}]");
@@ -12,7 +12,9 @@ use rskafka::record::{Record, RecordAndOffset};
 use tokio::spawn;
 use tokio::task::JoinHandle;
 use tracing::{debug, error, instrument, warn};
-use wasmcloud_provider_sdk::{get_connection, run_provider, Context, LinkConfig, Provider};
+use wasmcloud_provider_sdk::{
+    get_connection, run_provider, Context, LinkConfig, LinkDeleteInfo, Provider,
+};
 use wasmcloud_tracing::context::TraceContextInjector;
 
 use crate::wasmcloud::messaging::types::BrokerMessage;
@@ -178,7 +180,10 @@ impl Provider for KafkaMessagingProvider {
 
     /// Handle notification that a link is dropped: close the connection
     #[instrument(skip(self))]
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         debug!("deleting link for component {}", source_id);
 
         let mut connections = self.connections.write().unwrap();
@@ -18,7 +18,7 @@ use wasmcloud::messaging::types::BrokerMessage;
 use wasmcloud_provider_sdk::core::HostData;
 use wasmcloud_provider_sdk::wasmcloud_tracing::context::TraceContextInjector;
 use wasmcloud_provider_sdk::{
-    get_connection, load_host_data, run_provider, Context, LinkConfig, Provider,
+    get_connection, load_host_data, run_provider, Context, LinkConfig, LinkDeleteInfo, Provider,
 };
 
 mod connection;
@@ -326,18 +326,12 @@ impl Provider for NatsMessagingProvider {
         Ok(())
     }
 
-    /// Handle notification that a link is dropped: close the connection
+    /// Handle notification that a link to a handler component is dropped: close the connection
     #[instrument(level = "info", skip(self))]
-    async fn delete_link(&self, component_id: &str) -> anyhow::Result<()> {
-        if component_id == get_connection().provider_key() {
-            return self.delete_link_as_source(component_id).await;
-        }
-
-        self.delete_link_as_target(component_id).await
-    }
-
-    #[instrument(level = "info", skip(self))]
-    async fn delete_link_as_source(&self, target_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_source(
+        &self,
+        LinkDeleteInfo { target_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         let mut links = self.handler_components.write().await;
         if let Some(bundle) = links.remove(target_id) {
             // Note: subscriptions will be closed via Drop on the NatsClientBundle
@@ -362,8 +356,12 @@ impl Provider for NatsMessagingProvider {
         Ok(())
     }
 
+    /// Handle notification that a link from a consumer component is dropped: close the connection
     #[instrument(level = "info", skip(self))]
-    async fn delete_link_as_target(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         let mut links = self.consumer_components.write().await;
         if let Some(bundle) = links.remove(source_id) {
             let client = &bundle.client;
@@ -0,0 +1,75 @@
+//! Build metadata of providers, reported in health checks
+//!
+//! Use [`build_info!`](crate::build_info!) to capture the metadata of the provider crate at build
+//! time and return it from [`Provider::build_info`](crate::Provider::build_info). The SDK then adds
+//! it to the `details` of every health check response, which hosts publish in their health check
+//! events, so operators can audit exactly what is running in the lattice.
+
+use std::collections::HashMap;
+
+/// Version of the provider SDK the provider was built with
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Build metadata of a provider
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Name of the provider crate
+    pub name: &'static str,
+    /// Version of the provider crate
+    pub version: &'static str,
+    /// Git commit the provider was built from, if known
+    pub git_sha: Option<&'static str>,
+    /// Version of the provider SDK the provider was built with
+    pub sdk_version: &'static str,
+    /// Versioned WIT packages or interfaces the provider implements or uses, e.g.
+    /// `wasi:keyvalue/store@0.2.0-draft`
+    pub contracts: &'static [&'static str],
+}
+
+impl BuildInfo {
+    /// Build metadata as health check details, with keys prefixed by `build_`
+    #[must_use]
+    pub fn details(&self) -> HashMap<String, String> {
+        let mut details = HashMap::from([
+            ("build_name".to_string(), self.name.to_string()),
+            ("build_version".to_string(), self.version.to_string()),
+            (
+                "build_sdk_version".to_string(),
+                self.sdk_version.to_string(),
+            ),
+        ]);
+        if let Some(git_sha) = self.git_sha {
+            details.insert("build_git_sha".to_string(), git_sha.to_string());
+        }
+        if !self.contracts.is_empty() {
+            details.insert("build_contracts".to_string(), self.contracts.join(","));
+        }
+        details
+    }
+}
+
+/// Capture the [`BuildInfo`] of the crate this is invoked in, optionally listing the contracts the
+/// provider implements or uses
+///
+/// The git commit is read from the `GIT_SHA` environment variable at build time, which the build
+/// script of the provider may set with `cargo:rustc-env=GIT_SHA=...`.
+///
+/// # Example
+///
+/// ```ignore
+/// fn build_info(&self) -> Option<BuildInfo> {
+///     Some(build_info!("wasi:keyvalue/store@0.2.0-draft", "wasi:keyvalue/atomics@0.2.0-draft"))
+/// }
+/// ```
+#[macro_export]
+macro_rules! build_info {
+    ($($contract:literal),* $(,)?) => {
+        $crate::build_info::BuildInfo {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            git_sha: option_env!("GIT_SHA"),
+            sdk_version: $crate::build_info::SDK_VERSION,
+            contracts: &[$($contract),*],
+        }
+    };
+}
@@ -0,0 +1,67 @@
+//! Fetching named configuration from the lattice at runtime
+//!
+//! Hosts store named configuration by name as maps of strings. Providers normally only receive the
+//! config attached to them and to their links, but may fetch and watch any named config through
+//! [`ProviderConnection::get_named_config`](crate::ProviderConnection::get_named_config) and
+//! [`ProviderConnection::watch_named_config`](crate::ProviderConnection::watch_named_config).
+//! Hosts serve it over the lattice RPC connection, so this works regardless of how the host
+//! connects to the config store.
+
+use core::future::Future;
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context as _};
+use bytes::Bytes;
+use futures::StreamExt;
+use tracing::warn;
+use wasmcloud_core::rpc::named_config_get_subject;
+
+fn decode_config(name: &str, data: &[u8]) -> anyhow::Result<Option<HashMap<String, String>>> {
+    serde_json::from_slice(data).with_context(|| format!("failed to decode config '{name}'"))
+}
+
+/// Get the named config `name` from a host running the provider, or `None` if it does not exist
+pub(crate) async fn get_config(
+    nats: &async_nats::Client,
+    lattice: &str,
+    provider_id: &str,
+    name: &str,
+) -> anyhow::Result<Option<HashMap<String, String>>> {
+    let res = nats
+        .request(
+            named_config_get_subject(lattice, provider_id),
+            Bytes::from(name.to_string()),
+        )
+        .await
+        .map_err(|err| anyhow!(err).context(format!("failed to fetch config '{name}'")))?;
+    decode_config(name, &res.payload)
+}
+
+/// Call `handler` with the current value of the named config `name`, if it exists, and then on
+/// every change to it received on `changes`, with `None` when it is deleted, until the
+/// subscription ends
+pub(crate) async fn watch_config<F, Fut>(
+    nats: &async_nats::Client,
+    lattice: &str,
+    provider_id: &str,
+    mut changes: async_nats::Subscriber,
+    name: String,
+    handler: F,
+) where
+    F: Fn(Option<HashMap<String, String>>) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    // Changes are subscribed to before fetching the current value, so that none are missed
+    match get_config(nats, lattice, provider_id, &name).await {
+        Ok(Some(config)) => handler(Some(config)).await,
+        Ok(None) => {}
+        Err(err) => warn!(%err, %name, "failed to fetch current config"),
+    }
+    while let Some(msg) = changes.next().await {
+        match decode_config(&name, &msg.payload) {
+            Ok(config) => handler(config).await,
+            Err(err) => warn!(%err, %name, "ignoring invalid config update"),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Reacting to events published on the lattice by hosts
+//!
+//! Hosts publish [CloudEvents](https://cloudevents.io) on `wasmbus.evt.<lattice>.<event>` whenever
+//! the topology of the lattice changes. Implement [`LatticeEventHandler`] and pass it to
+//! [`ProviderConnection::subscribe_lattice_events`](crate::ProviderConnection::subscribe_lattice_events)
+//! to react to those changes, for example to clean up sessions of stopped components.
+
+use core::future::Future;
+
+use std::collections::BTreeMap;
+
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tracing::{trace, warn};
+
+use crate::{WitInterface, WitNamespace, WitPackage};
+
+/// Generate the subject on which hosts publish lattice events
+pub(crate) fn lattice_events_subject(lattice: &str) -> String {
+    format!("wasmbus.evt.{lattice}.>")
+}
+
+/// A component was scaled on a host, published as `component_scaled`
+#[derive(Clone, Debug, Deserialize)]
+pub struct ComponentScaledEvent {
+    /// ID of the component
+    pub component_id: String,
+    /// ID of the host the component was scaled on
+    pub host_id: String,
+    /// Image reference of the component
+    pub image_ref: String,
+    /// Maximum number of concurrent instances, `0` if the component was stopped
+    pub max_instances: usize,
+    /// Annotations of the component
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// A link was put on the lattice, published as `linkdef_set`
+#[derive(Clone, Debug, Deserialize)]
+pub struct LinkSetEvent {
+    /// ID of the source of the link
+    pub source_id: String,
+    /// ID of the target of the link
+    pub target: String,
+    /// Name of the link
+    pub name: String,
+    /// WIT namespace of the link
+    pub wit_namespace: WitNamespace,
+    /// WIT package of the link
+    pub wit_package: WitPackage,
+    /// WIT interfaces of the link
+    pub interfaces: Vec<WitInterface>,
+}
+
+/// A link was deleted from the lattice, published as `linkdef_deleted`
+#[derive(Clone, Debug, Deserialize)]
+pub struct LinkDeletedEvent {
+    /// ID of the source of the link
+    pub source_id: String,
+    /// Name of the link
+    pub name: String,
+    /// WIT namespace of the link
+    pub wit_namespace: WitNamespace,
+    /// WIT package of the link
+    pub wit_package: WitPackage,
+}
+
+/// A provider was started on a host, published as `provider_started`
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProviderStartedEvent {
+    /// ID of the provider
+    pub provider_id: String,
+    /// ID of the host the provider was started on
+    pub host_id: String,
+    /// Image reference of the provider
+    pub image_ref: String,
+    /// Annotations of the provider
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// A provider was stopped on a host, published as `provider_stopped`
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProviderStoppedEvent {
+    /// ID of the provider
+    pub provider_id: String,
+    /// ID of the host the provider was stopped on
+    pub host_id: String,
+    /// Reason the provider was stopped
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// Handler of events published on the lattice
+///
+/// All methods default to ignoring the event, so only the events of interest need implementing.
+pub trait LatticeEventHandler: Send + Sync + 'static {
+    /// Called when a component is scaled to a nonzero number of instances on a host
+    fn on_component_scaled(&self, event: ComponentScaledEvent) -> impl Future<Output = ()> + Send {
+        let _ = event;
+        async {}
+    }
+
+    /// Called when a component is stopped, i.e. scaled to zero instances, on a host
+    fn on_component_stopped(&self, event: ComponentScaledEvent) -> impl Future<Output = ()> + Send {
+        let _ = event;
+        async {}
+    }
+
+    /// Called when a link is put anywhere on the lattice
+    fn on_link_set(&self, event: LinkSetEvent) -> impl Future<Output = ()> + Send {
+        let _ = event;
+        async {}
+    }
+
+    /// Called when a link is deleted anywhere on the lattice
+    fn on_link_deleted(&self, event: LinkDeletedEvent) -> impl Future<Output = ()> + Send {
+        let _ = event;
+        async {}
+    }
+
+    /// Called when a provider is started on a host
+    fn on_provider_started(&self, event: ProviderStartedEvent) -> impl Future<Output = ()> + Send {
+        let _ = event;
+        async {}
+    }
+
+    /// Called when a provider is stopped on a host
+    fn on_provider_stopped(&self, event: ProviderStoppedEvent) -> impl Future<Output = ()> + Send {
+        let _ = event;
+        async {}
+    }
+}
+
+/// The parts of a lattice CloudEvent needed to dispatch it
+#[derive(Deserialize)]
+struct LatticeEvent {
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+fn parse_data<T: DeserializeOwned>(ty: &str, data: serde_json::Value) -> Option<T> {
+    serde_json::from_value(data)
+        .map_err(|err| warn!(%err, ty, "failed to parse lattice event data"))
+        .ok()
+}
+
+/// Dispatch events received on `sub` to `handler` until the subscription ends
+pub(crate) async fn handle_lattice_events(
+    mut sub: async_nats::Subscriber,
+    handler: impl LatticeEventHandler,
+) {
+    while let Some(msg) = sub.next().await {
+        let LatticeEvent { ty, data } = match serde_json::from_slice(&msg.payload) {
+            Ok(event) => event,
+            Err(err) => {
+                warn!(%err, subject = %msg.subject, "received invalid lattice event");
+                continue;
+            }
+        };
+        match ty.strip_prefix("com.wasmcloud.lattice.").unwrap_or(&ty) {
+            "component_scaled" => {
+                if let Some(event) = parse_data::<ComponentScaledEvent>(&ty, data) {
+                    if event.max_instances == 0 {
+                        handler.on_component_stopped(event).await;
+                    } else {
+                        handler.on_component_scaled(event).await;
+                    }
+                }
+            }
+            "linkdef_set" => {
+                if let Some(event) = parse_data(&ty, data) {
+                    handler.on_link_set(event).await;
+                }
+            }
+            "linkdef_deleted" => {
+                if let Some(event) = parse_data(&ty, data) {
+                    handler.on_link_deleted(event).await;
+                }
+            }
+            "provider_started" => {
+                if let Some(event) = parse_data(&ty, data) {
+                    handler.on_provider_started(event).await;
+                }
+            }
+            "provider_stopped" => {
+                if let Some(event) = parse_data(&ty, data) {
+                    handler.on_provider_stopped(event).await;
+                }
+            }
+            _ => trace!(ty, "ignoring lattice event"),
+        }
+    }
+}
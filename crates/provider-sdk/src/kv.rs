@@ -0,0 +1,154 @@
+//! Typed access to NATS JetStream key-value buckets for shared, durable provider state
+//!
+//! [`ProviderConnection::kv_bucket`](crate::ProviderConnection::kv_bucket) opens (or creates) a
+//! bucket on the lattice connection of the provider as a [`KvBucket`], which stores values as JSON.
+
+use core::future::Future;
+use core::marker::PhantomData;
+
+use anyhow::{anyhow, Context as _};
+use async_nats::jetstream::kv::{Config, Operation, Store};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::spawn;
+use tokio::task::JoinHandle;
+use tracing::{error, instrument, warn, Instrument as _};
+
+/// Get the key-value bucket described by `config`, creating it if it does not exist yet
+#[instrument(level = "debug", skip_all, fields(bucket = %config.bucket))]
+pub(crate) async fn get_or_create_bucket(
+    jetstream: &async_nats::jetstream::Context,
+    config: Config,
+) -> anyhow::Result<Store> {
+    if let Ok(store) = jetstream.get_key_value(&config.bucket).await {
+        return Ok(store);
+    }
+    let bucket = config.bucket.clone();
+    jetstream
+        .create_key_value(config)
+        .await
+        .map_err(|err| anyhow!(err).context(format!("failed to create bucket '{bucket}'")))
+}
+
+/// A JetStream key-value bucket holding JSON-encoded values of type `T`
+pub struct KvBucket<T> {
+    store: Store,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for KvBucket<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> KvBucket<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub(crate) fn new(store: Store) -> Self {
+        Self {
+            store,
+            _value: PhantomData,
+        }
+    }
+
+    /// Get the value stored under `key`, or `None` if there is none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be fetched or decoded
+    pub async fn get(&self, key: &str) -> anyhow::Result<Option<T>> {
+        let Some(data) = self
+            .store
+            .get(key)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to get key '{key}'")))?
+        else {
+            return Ok(None);
+        };
+        serde_json::from_slice(&data)
+            .with_context(|| format!("failed to decode value of key '{key}'"))
+            .map(Some)
+    }
+
+    /// Store `value` under `key`, returning the revision of the new entry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be encoded or stored
+    pub async fn put(&self, key: &str, value: &T) -> anyhow::Result<u64> {
+        let data = serde_json::to_vec(value)
+            .with_context(|| format!("failed to encode value of key '{key}'"))?;
+        self.store
+            .put(key, data.into())
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to put key '{key}'")))
+    }
+
+    /// Delete the value stored under `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value could not be deleted
+    pub async fn delete(&self, key: &str) -> anyhow::Result<()> {
+        self.store
+            .delete(key)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to delete key '{key}'")))
+    }
+
+    /// Watch the keys matching `key`, which may contain wildcards (e.g. `sessions.>`), calling
+    /// `handler` with the key and its new value, or `None` if it was deleted, on every change
+    ///
+    /// Values that fail to decode are logged and skipped. The watch runs on a spawned task until
+    /// the returned handle is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the watch could not be started
+    pub async fn watch<F, Fut>(&self, key: &str, handler: F) -> anyhow::Result<JoinHandle<()>>
+    where
+        T: Send + 'static,
+        F: Fn(String, Option<T>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut watch = self
+            .store
+            .watch(key)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to watch key '{key}'")))?;
+        Ok(spawn(
+            async move {
+                while let Some(entry) = watch.next().await {
+                    let entry = match entry {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            error!(%err, "failed to receive key-value update");
+                            continue;
+                        }
+                    };
+                    if matches!(entry.operation, Operation::Delete | Operation::Purge) {
+                        handler(entry.key, None).await;
+                        continue;
+                    }
+                    match serde_json::from_slice(&entry.value) {
+                        Ok(value) => handler(entry.key, Some(value)).await,
+                        Err(err) => warn!(%err, key = %entry.key, "ignoring invalid value"),
+                    }
+                }
+            }
+            .instrument(tracing::debug_span!("kv_watch", key)),
+        ))
+    }
+
+    /// Get the underlying [`Store`], e.g. for compare-and-swap updates or history
+    #[must_use]
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+}
@@ -0,0 +1,115 @@
+//! Leader election between instances of a provider running on different hosts
+//!
+//! Instances campaign for a lease stored in a NATS JetStream key-value bucket. The leader renews
+//! the lease well within its time to live, and other instances take over once it expires, e.g.
+//! because the leader's host stopped. Implement [`LeadershipHandler`] and pass it to
+//! [`ProviderConnection::elect_leader`](crate::ProviderConnection::elect_leader) to run tasks
+//! such as firing schedules on exactly one instance.
+
+use core::future::Future;
+use core::time::Duration;
+
+use anyhow::Context as _;
+use async_nats::jetstream::kv::{Config, Store};
+use bytes::Bytes;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+use crate::kv::get_or_create_bucket;
+
+/// Handler of leadership changes of a provider instance
+pub trait LeadershipHandler: Send + Sync + 'static {
+    /// Called when this instance becomes the leader
+    fn on_leadership_gained(&self) -> impl Future<Output = ()> + Send;
+
+    /// Called when this instance stops being the leader, because its lease could not be renewed
+    fn on_leadership_lost(&self) -> impl Future<Output = ()> + Send;
+}
+
+/// Name of the bucket holding leases of provider leader elections on `lattice` that expire after
+/// `ttl`
+///
+/// The maximum age of entries is fixed when a bucket is created, so elections with different TTLs
+/// use different buckets.
+fn leader_bucket_name(lattice: &str, ttl: Duration) -> String {
+    format!("PROVIDER_LEADERS_{lattice}_{}", ttl.as_millis())
+}
+
+/// Get the bucket holding leases of provider leader elections on a lattice with leases expiring
+/// after `ttl`, creating it if needed
+///
+/// Entries of the bucket expire after `ttl`, which is what releases the lease of a leader that
+/// stopped renewing it.
+pub(crate) async fn leader_bucket(
+    nats: &async_nats::Client,
+    lattice: &str,
+    ttl: Duration,
+) -> anyhow::Result<Store> {
+    let jetstream = async_nats::jetstream::new(nats.clone());
+    let store = get_or_create_bucket(
+        &jetstream,
+        Config {
+            bucket: leader_bucket_name(lattice, ttl),
+            history: 1,
+            max_age: ttl,
+            ..Default::default()
+        },
+    )
+    .await
+    .context("failed to get leader election bucket")?;
+    Ok(store)
+}
+
+/// Campaign for the lease stored under `key` as `candidate` until the task is aborted, renewing
+/// it every third of `ttl` while leading
+pub(crate) async fn run_election(
+    store: Store,
+    key: String,
+    candidate: String,
+    ttl: Duration,
+    handler: impl LeadershipHandler,
+) {
+    let mut interval = tokio::time::interval(ttl / 3);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut revision = None;
+    loop {
+        interval.tick().await;
+        let value = Bytes::from(candidate.clone());
+        match revision {
+            None => match store.create(&key, value).await {
+                Ok(rev) => {
+                    info!(%key, "gained leadership");
+                    revision = Some(rev);
+                    handler.on_leadership_gained().await;
+                }
+                Err(err) => debug!(%err, %key, "leadership is held by another instance"),
+            },
+            Some(rev) => match store.update(&key, value, rev).await {
+                Ok(rev) => revision = Some(rev),
+                Err(err) => {
+                    warn!(%err, %key, "failed to renew leadership lease, lost leadership");
+                    revision = None;
+                    handler.on_leadership_lost().await;
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leader_bucket_per_ttl() {
+        let short = leader_bucket_name("default", Duration::from_secs(5));
+        let long = leader_bucket_name("default", Duration::from_secs(30));
+        assert_eq!(short, "PROVIDER_LEADERS_default_5000");
+        assert_eq!(long, "PROVIDER_LEADERS_default_30000");
+        assert_ne!(short, long);
+        assert_eq!(
+            leader_bucket_name("default", Duration::from_millis(5000)),
+            short
+        );
+    }
+}
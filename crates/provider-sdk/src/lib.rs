@@ -11,19 +11,34 @@ use tower::ServiceExt;
 use tracing::{error, info, warn};
 use wrpc_transport::{AcceptedInvocation, IncomingInvocation, OutgoingInvocation};
 
+pub mod build_info;
+pub mod config;
 pub mod error;
+pub mod events;
 pub mod interfaces;
+pub mod kv;
+pub mod leader;
+pub mod links;
+pub mod object_store;
 pub mod provider;
+pub mod pubsub;
+pub mod schedule;
+pub mod secrets;
 
 #[cfg(feature = "otel")]
 pub mod otel;
 
-pub use provider::{get_connection, load_host_data, run_provider, ProviderConnection};
+pub use build_info::BuildInfo;
+pub use links::{LinkedSources, LinkedTargets};
+pub use provider::{
+    get_connection, load_host_data, run_provider, run_provider_with_secrets, ProviderConnection,
+};
+pub use schedule::Schedule;
 pub use wasmcloud_core as core;
 /// Re-export of types from [`wasmcloud_core`]
 pub use wasmcloud_core::{
-    HealthCheckRequest, HealthCheckResponse, InterfaceLinkDefinition, WitFunction, WitInterface,
-    WitNamespace, WitPackage,
+    HealthCheckRequest, HealthCheckResponse, InterfaceLinkDefinition, SecretValue, ShutdownReason,
+    WitFunction, WitInterface, WitNamespace, WitPackage,
 };
 pub use wasmcloud_tracing;
 
@@ -82,16 +97,19 @@ pub const DEFAULT_RPC_TIMEOUT_MILLIS: Duration = Duration::from_millis(2000);
 /// helper method to add logging to a nats connection. Logs disconnection (warn level), reconnection (info level), error (error), slow consumer, and lame duck(warn) events.
 #[must_use]
 pub fn with_connection_event_logging(opts: ConnectOptions) -> ConnectOptions {
-    opts.event_callback(|event| async move {
-        match event {
-            Event::Disconnected => warn!("nats client disconnected"),
-            Event::Connected => info!("nats client connected"),
-            Event::ClientError(err) => error!("nats client error: '{:?}'", err),
-            Event::ServerError(err) => error!("nats server error: '{:?}'", err),
-            Event::SlowConsumer(val) => warn!("nats slow consumer detected ({})", val),
-            Event::LameDuckMode => warn!("nats lame duck mode"),
-        }
-    })
+    opts.event_callback(|event| async move { log_connection_event(&event) })
+}
+
+/// Log a nats connection event at the level used by [`with_connection_event_logging`]
+pub(crate) fn log_connection_event(event: &Event) {
+    match event {
+        Event::Disconnected => warn!("nats client disconnected"),
+        Event::Connected => info!("nats client connected"),
+        Event::ClientError(err) => error!("nats client error: '{:?}'", err),
+        Event::ServerError(err) => error!("nats server error: '{:?}'", err),
+        Event::SlowConsumer(val) => warn!("nats slow consumer detected ({})", val),
+        Event::LameDuckMode => warn!("nats lame duck mode"),
+    }
 }
 
 /// Context - message passing metadata used by wasmCloud Capability Providers
@@ -120,10 +138,30 @@ pub struct LinkConfig<'a> {
     /// Configuration provided to the provider (either as the target or the source)
     pub config: &'a HashMap<String, String>,
 
+    /// Secrets referenced in `config`, keyed by config key without the
+    /// [`SECRET_REFERENCE_SUFFIX`](secrets::SECRET_REFERENCE_SUFFIX). These are only resolved for
+    /// providers started with [`run_provider_with_secrets`] and are empty otherwise
+    pub secrets: &'a HashMap<String, SecretValue>,
+
     /// WIT metadata for the link
     pub wit_metadata: (&'a WitNamespace, &'a WitPackage, &'a Vec<WitInterface>),
 }
 
+/// Information about a link that was deleted, passed to a provider
+pub struct LinkDeleteInfo<'a> {
+    /// Source ID of the deleted link, which is this provider if it was the source
+    pub source_id: &'a str,
+
+    /// Target ID of the deleted link, which is this provider if it was the target
+    pub target_id: &'a str,
+
+    /// Name of the deleted link
+    pub link_name: &'a str,
+
+    /// WIT metadata for the deleted link
+    pub wit_metadata: (&'a WitNamespace, &'a WitPackage, &'a Vec<WitInterface>),
+}
+
 /// Configuration object is made available when a provider is started, to assist in init
 ///
 /// This trait exists to both obscure the underlying implementation and control what information
@@ -196,32 +234,71 @@ pub trait Provider<E = anyhow::Error>: Sync {
         async { Ok(()) }
     }
 
-    /// Notify the provider that the link is dropped
-    fn delete_link(&self, component_id: &str) -> impl Future<Output = Result<(), E>> + Send {
-        let _ = component_id;
-        async { Ok(()) }
-    }
-
     /// Notify the provider that the link is dropped where the provider is the target
+    ///
+    /// The link name and WIT metadata in `info` identify which link was dropped when the same
+    /// component is linked to the provider more than once.
     fn delete_link_as_target(
         &self,
-        component_id: &str,
+        info: LinkDeleteInfo<'_>,
     ) -> impl Future<Output = Result<(), E>> + Send {
-        let _ = component_id;
+        let _ = info;
         async { Ok(()) }
     }
 
     /// Notify the provider that the link is dropped where the provider is the source
+    ///
+    /// The link name and WIT metadata in `info` identify which link was dropped when the
+    /// provider is linked to the same component more than once.
     fn delete_link_as_source(
         &self,
-        component_id: &str,
+        info: LinkDeleteInfo<'_>,
     ) -> impl Future<Output = Result<(), E>> + Send {
-        let _ = component_id;
+        let _ = info;
         async { Ok(()) }
     }
 
+    /// Receive and handle updated configuration for the provider
+    ///
+    /// Called whenever any of the named configuration attached to the provider changes, with the
+    /// newly merged configuration. Implement this to rotate credentials or tune connection pools
+    /// without restarting the provider. Returning an error keeps the previous configuration
+    /// available from [`ProviderConnection::config`].
+    fn receive_config_update(
+        &self,
+        config: &HashMap<String, String>,
+    ) -> impl Future<Output = Result<(), E>> + Send {
+        let _ = config;
+        async { Ok(()) }
+    }
+
+    /// Handle the lattice connection of the provider being re-established after it was lost
+    ///
+    /// Subscriptions made through the SDK are restored by the NATS client itself, and links put or
+    /// deleted while the provider was disconnected are received or deleted before this is called,
+    /// so implement this to resynchronize any other state that may have gone stale.
+    ///
+    /// This is also called after the SDK replaced the lattice connection to rotate the client
+    /// certificate. Clients previously obtained from [`ProviderConnection`], such as those serving
+    /// wRPC exports, keep the previous connection open until they are dropped, so obtain new ones
+    /// here.
+    fn on_reconnect(&self) -> impl Future<Output = Result<(), E>> + Send {
+        async { Ok(()) }
+    }
+
+    /// Build metadata of the provider, usually captured with [`build_info!`], which is added to
+    /// the `details` of every health check response
+    ///
+    /// Default implementation reports no build metadata
+    fn build_info(&self) -> Option<BuildInfo> {
+        None
+    }
+
     /// Perform health check. Called at regular intervals by host
     /// Default implementation always returns healthy
+    ///
+    /// Override this to report backend connectivity, queue depth, etc. through the `message` and
+    /// `details` of the response, which the host includes in the health check events it publishes
     fn health_request(
         &self,
         _arg: &HealthCheckRequest,
@@ -229,7 +306,7 @@ pub trait Provider<E = anyhow::Error>: Sync {
         async {
             Ok(HealthCheckResponse {
                 healthy: true,
-                message: None,
+                ..Default::default()
             })
         }
     }
@@ -238,6 +315,18 @@ pub trait Provider<E = anyhow::Error>: Sync {
     fn shutdown(&self) -> impl Future<Output = Result<(), E>> + Send {
         async { Ok(()) }
     }
+
+    /// Handle a shutdown requested by the host for `reason`, e.g. to choose between a fast
+    /// teardown and flushing all state
+    ///
+    /// Default implementation calls [`Provider::shutdown`]
+    fn shutdown_with_reason(
+        &self,
+        reason: ShutdownReason,
+    ) -> impl Future<Output = Result<(), E>> + Send {
+        let _ = reason;
+        self.shutdown()
+    }
 }
 
 #[derive(Clone, Debug)]
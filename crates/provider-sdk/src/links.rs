@@ -0,0 +1,165 @@
+//! Helpers for keeping per-link state in providers
+//!
+//! Most providers keep some state (clients, connections, parsed config) for every link they are
+//! part of. [`LinkedSources`] and [`LinkedTargets`] wrap the shared map such state is normally
+//! kept in, and are keyed by the types received in the [`Provider`](crate::Provider) lifecycle
+//! methods, so that state is inserted on link put, removed on link delete and looked up by
+//! the [`Context`] of an invocation.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::{RwLock, RwLockReadGuard};
+
+use crate::{Context, LinkConfig, LinkDeleteInfo};
+
+/// Per-link state, keyed by the ID of the component on the other end of the link
+#[derive(Debug)]
+struct LinkStateMap<T>(Arc<RwLock<HashMap<String, T>>>);
+
+impl<T> Clone for LinkStateMap<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Default for LinkStateMap<T> {
+    fn default() -> Self {
+        Self(Arc::default())
+    }
+}
+
+impl<T> LinkStateMap<T> {
+    async fn insert(&self, id: &str, state: T) -> Option<T> {
+        self.0.write().await.insert(id.to_string(), state)
+    }
+
+    async fn remove(&self, id: &str) -> Option<T> {
+        self.0.write().await.remove(id)
+    }
+
+    async fn get(&self, id: &str) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.0.read().await.get(id).cloned()
+    }
+
+    async fn read(&self) -> RwLockReadGuard<'_, HashMap<String, T>> {
+        self.0.read().await
+    }
+
+    async fn drain(&self) -> Vec<(String, T)> {
+        self.0.write().await.drain().collect()
+    }
+}
+
+/// Per-link state of a provider for links where it is the target, keyed by the source component ID
+///
+/// Use this from [`Provider::receive_link_config_as_target`](crate::Provider::receive_link_config_as_target)
+/// and [`Provider::delete_link_as_target`](crate::Provider::delete_link_as_target).
+#[derive(Debug)]
+pub struct LinkedSources<T>(LinkStateMap<T>);
+
+impl<T> Clone for LinkedSources<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for LinkedSources<T> {
+    fn default() -> Self {
+        Self(LinkStateMap::default())
+    }
+}
+
+impl<T> LinkedSources<T> {
+    /// Store state for the source of a newly put link, returning the state it replaced, if any
+    pub async fn insert(&self, config: &LinkConfig<'_>, state: T) -> Option<T> {
+        self.0.insert(config.source_id, state).await
+    }
+
+    /// Remove and return the state for the source of a deleted link
+    pub async fn remove(&self, info: &LinkDeleteInfo<'_>) -> Option<T> {
+        self.0.remove(info.source_id).await
+    }
+
+    /// Get the state for a source component
+    pub async fn get(&self, source_id: &str) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.0.get(source_id).await
+    }
+
+    /// Get the state for the component that sent an invocation
+    ///
+    /// Returns `None` if there is no context, the context does not identify the calling
+    /// component, or the calling component is not linked to the provider.
+    pub async fn get_for_context(&self, context: Option<&Context>) -> Option<T>
+    where
+        T: Clone,
+    {
+        let source_id = context?.component.as_deref()?;
+        self.0.get(source_id).await
+    }
+
+    /// Acquire a read lock on all per-link state, keyed by source component ID
+    pub async fn read(&self) -> RwLockReadGuard<'_, HashMap<String, T>> {
+        self.0.read().await
+    }
+
+    /// Remove and return all per-link state, e.g. to clean up on [`Provider::shutdown`](crate::Provider::shutdown)
+    pub async fn drain(&self) -> Vec<(String, T)> {
+        self.0.drain().await
+    }
+}
+
+/// Per-link state of a provider for links where it is the source, keyed by the target component ID
+///
+/// Use this from [`Provider::receive_link_config_as_source`](crate::Provider::receive_link_config_as_source)
+/// and [`Provider::delete_link_as_source`](crate::Provider::delete_link_as_source).
+#[derive(Debug)]
+pub struct LinkedTargets<T>(LinkStateMap<T>);
+
+impl<T> Clone for LinkedTargets<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for LinkedTargets<T> {
+    fn default() -> Self {
+        Self(LinkStateMap::default())
+    }
+}
+
+impl<T> LinkedTargets<T> {
+    /// Store state for the target of a newly put link, returning the state it replaced, if any
+    pub async fn insert(&self, config: &LinkConfig<'_>, state: T) -> Option<T> {
+        self.0.insert(config.target_id, state).await
+    }
+
+    /// Remove and return the state for the target of a deleted link
+    pub async fn remove(&self, info: &LinkDeleteInfo<'_>) -> Option<T> {
+        self.0.remove(info.target_id).await
+    }
+
+    /// Get the state for a target component
+    pub async fn get(&self, target_id: &str) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.0.get(target_id).await
+    }
+
+    /// Acquire a read lock on all per-link state, keyed by target component ID
+    pub async fn read(&self) -> RwLockReadGuard<'_, HashMap<String, T>> {
+        self.0.read().await
+    }
+
+    /// Remove and return all per-link state, e.g. to clean up on [`Provider::shutdown`](crate::Provider::shutdown)
+    pub async fn drain(&self) -> Vec<(String, T)> {
+        self.0.drain().await
+    }
+}
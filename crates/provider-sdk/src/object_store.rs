@@ -0,0 +1,98 @@
+//! Access to NATS JetStream object stores for artifacts too large for a single message
+//!
+//! [`ProviderConnection::object_bucket`](crate::ProviderConnection::object_bucket) opens (or
+//! creates) an object store on the lattice connection of the provider as an [`ObjectBucket`].
+//! Objects are streamed in chunks, so their size is not bound by the NATS maximum payload.
+
+use anyhow::anyhow;
+use async_nats::jetstream::object_store::{Config, Object, ObjectInfo, ObjectStore};
+use tokio::io::AsyncRead;
+use tracing::instrument;
+
+/// Get the object store described by `config`, creating it if it does not exist yet
+#[instrument(level = "debug", skip_all, fields(bucket = %config.bucket))]
+pub(crate) async fn get_or_create_object_store(
+    jetstream: &async_nats::jetstream::Context,
+    config: Config,
+) -> anyhow::Result<ObjectStore> {
+    if let Ok(store) = jetstream.get_object_store(&config.bucket).await {
+        return Ok(store);
+    }
+    let bucket = config.bucket.clone();
+    jetstream
+        .create_object_store(config)
+        .await
+        .map_err(|err| anyhow!(err).context(format!("failed to create object store '{bucket}'")))
+}
+
+/// A JetStream object store
+#[derive(Clone)]
+pub struct ObjectBucket {
+    store: ObjectStore,
+}
+
+impl ObjectBucket {
+    pub(crate) fn new(store: ObjectStore) -> Self {
+        Self { store }
+    }
+
+    /// Upload the object `name`, streaming its contents from `data`, and return its info,
+    /// including size and digest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object could not be uploaded
+    pub async fn put(
+        &self,
+        name: &str,
+        data: &mut (impl AsyncRead + Unpin),
+    ) -> anyhow::Result<ObjectInfo> {
+        self.store
+            .put(name, data)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to put object '{name}'")))
+    }
+
+    /// Download the object `name`, whose contents are streamed by reading from the returned
+    /// [`Object`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object does not exist or could not be fetched
+    pub async fn get(&self, name: &str) -> anyhow::Result<Object> {
+        self.store
+            .get(name)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to get object '{name}'")))
+    }
+
+    /// Get the info of the object `name`, including size and digest, without downloading it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object does not exist or its info could not be fetched
+    pub async fn info(&self, name: &str) -> anyhow::Result<ObjectInfo> {
+        self.store
+            .info(name)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to get info of object '{name}'")))
+    }
+
+    /// Delete the object `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object could not be deleted
+    pub async fn delete(&self, name: &str) -> anyhow::Result<()> {
+        self.store
+            .delete(name)
+            .await
+            .map_err(|err| anyhow!(err).context(format!("failed to delete object '{name}'")))
+    }
+
+    /// Get the underlying [`ObjectStore`], e.g. to list or watch objects
+    #[must_use]
+    pub fn store(&self) -> &ObjectStore {
+        &self.store
+    }
+}
@@ -1,42 +1,68 @@
 use core::fmt;
 use core::fmt::Formatter;
 use core::future::Future;
+use core::mem;
 
 use core::time::Duration;
 use std::collections::HashMap;
 use std::io::BufRead;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::SystemTime;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context as _, Result};
 use async_nats::subject::ToSubject;
 use async_nats::HeaderMap;
 use base64::Engine;
+use bytes::Bytes;
+use futures::future::join_all;
+use futures::stream::{self, BoxStream};
 use futures::StreamExt;
 use once_cell::sync::OnceCell;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{broadcast, mpsc, oneshot, RwLock};
-use tokio::task::spawn_blocking;
+use tokio::sync::{broadcast, mpsc, oneshot, watch, Mutex, RwLock};
+use tokio::task::{spawn_blocking, AbortHandle, JoinHandle};
+use tokio::time::{interval_at, timeout, Instant, MissedTickBehavior};
 use tokio::{select, spawn, try_join};
 use tracing::{debug, error, info, instrument, trace, warn, Instrument as _};
 use wasmcloud_core::nats::convert_header_map_to_hashmap;
-use wasmcloud_core::rpc::{health_subject, link_del_subject, link_put_subject, shutdown_subject};
+use wasmcloud_core::rpc::{
+    health_subject, link_del_subject, link_get_subject, link_put_subject, log_filter_subject,
+    named_config_changed_subject, provider_config_update_subject, provider_log_subject,
+    shutdown_subject,
+};
 use wasmcloud_core::{
     HealthCheckRequest, HealthCheckResponse, HostData, InterfaceLinkDefinition, LatticeTarget,
+    LinkPutResponse, ShutdownReason,
 };
 
 #[cfg(feature = "otel")]
 use wasmcloud_core::TraceContext;
 #[cfg(feature = "otel")]
-use wasmcloud_tracing::context::attach_span_context;
+use wasmcloud_tracing::context::{attach_span_context, TraceContextInjector};
 
+use crate::config::{get_config, watch_config};
 use crate::error::{ProviderInitError, ProviderInitResult};
+use crate::events::{handle_lattice_events, lattice_events_subject, LatticeEventHandler};
+use crate::kv::{get_or_create_bucket, KvBucket};
+use crate::leader::{leader_bucket, run_election, LeadershipHandler};
+use crate::object_store::{get_or_create_object_store, ObjectBucket};
+use crate::pubsub::{event_handler, provider_event_subject};
+use crate::schedule::{run_schedule, schedule_bucket_name, Schedule};
+use crate::secrets::{resolve_secret_references, ProviderSecretsResolver, SecretsResolver};
 use crate::{
-    with_connection_event_logging, Context, LinkConfig, Provider, WrpcClient, DEFAULT_NATS_ADDR,
+    log_connection_event, Context, LinkConfig, LinkDeleteInfo, Provider, WrpcClient,
+    DEFAULT_NATS_ADDR,
 };
 
 /// Name of the header that should be passed for invocations that identifies the source
 const WRPC_SOURCE_ID_HEADER_NAME: &str = "source-id";
 
+/// Names of the W3C trace context headers carried on invocations
+const TRACE_CONTEXT_HEADER_NAMES: [&str; 2] = ["traceparent", "tracestate"];
+
 static HOST_DATA: OnceCell<HostData> = OnceCell::new();
 static CONNECTION: OnceCell<ProviderConnection> = OnceCell::new();
 
@@ -106,6 +132,9 @@ pub type QuitSignal = broadcast::Receiver<()>;
 struct ShutdownMessage {
     /// The ID of the host that sent the message
     pub host_id: String,
+    /// Why the host requested the shutdown
+    #[serde(default)]
+    pub reason: ShutdownReason,
 }
 
 #[doc(hidden)]
@@ -141,12 +170,11 @@ async fn subscribe_health(
     mut quit: broadcast::Receiver<()>,
     lattice: &str,
     provider_key: &str,
-) -> ProviderInitResult<mpsc::Receiver<(HealthCheckRequest, oneshot::Sender<HealthCheckResponse>)>>
-{
+    health_tx: mpsc::Sender<(HealthCheckRequest, oneshot::Sender<HealthCheckResponse>)>,
+) -> ProviderInitResult<()> {
     let mut sub = nats
         .subscribe(health_subject(lattice, provider_key))
         .await?;
-    let (health_tx, health_rx) = mpsc::channel(1);
     spawn({
         let nats = Arc::clone(&nats);
         async move {
@@ -176,24 +204,32 @@ async fn subscribe_health(
         }
         .instrument(tracing::debug_span!("subscribe_health"))
     });
-    Ok(health_rx)
+    Ok(())
 }
 
 async fn subscribe_shutdown(
     nats: Arc<async_nats::Client>,
     quit: broadcast::Sender<()>,
+    mut stop: broadcast::Receiver<()>,
     lattice: &str,
     provider_key: &str,
     host_id: &'static str,
-) -> ProviderInitResult<mpsc::Receiver<oneshot::Sender<()>>> {
+    shutdown_tx: mpsc::Sender<(ShutdownReason, oneshot::Sender<()>)>,
+) -> ProviderInitResult<()> {
     let mut sub = nats
         .subscribe(shutdown_subject(lattice, provider_key, "default"))
         .await?;
-    let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
     spawn({
         async move {
             loop {
-                let msg = sub.next().await;
+                let msg = select! {
+                    msg = sub.next() => msg,
+                    // The provider quit or the subscription moved to a new lattice connection
+                    _ = stop.recv() => {
+                        let _ = sub.unsubscribe().await;
+                        break;
+                    }
+                };
                 // Check if we really need to shut down
                 if let Some(async_nats::Message {
                     reply: Some(reply_to),
@@ -203,13 +239,14 @@ async fn subscribe_shutdown(
                 {
                     let ShutdownMessage {
                         host_id: ref req_host_id,
+                        reason,
                     } = serde_json::from_slice(&payload).unwrap_or_default();
                     if req_host_id == host_id {
-                        info!("Received termination signal and stopping");
+                        info!(?reason, "Received termination signal and stopping");
                         // Tell provider to shutdown - before we shut down nats subscriptions,
                         // in case it needs to do any message passing during shutdown
                         let (tx, rx) = oneshot::channel();
-                        match shutdown_tx.send(tx).await {
+                        match shutdown_tx.send((reason, tx)).await {
                             Ok(()) => {
                                 if let Err(err) = rx.await {
                                     error!(%err, "failed to await shutdown");
@@ -236,7 +273,7 @@ async fn subscribe_shutdown(
         }
         .instrument(tracing::debug_span!("shutdown_subscriber"))
     });
-    Ok(shutdown_rx)
+    Ok(())
 }
 
 async fn subscribe_link_put(
@@ -244,11 +281,11 @@ async fn subscribe_link_put(
     mut quit: broadcast::Receiver<()>,
     lattice: &str,
     provider_key: &str,
-) -> ProviderInitResult<mpsc::Receiver<(InterfaceLinkDefinition, oneshot::Sender<()>)>> {
+    link_put_tx: mpsc::Sender<(InterfaceLinkDefinition, oneshot::Sender<LinkPutResponse>)>,
+) -> ProviderInitResult<()> {
     let mut sub = nats
         .subscribe(link_put_subject(lattice, provider_key))
         .await?;
-    let (link_put_tx, link_put_rx) = mpsc::channel(1);
     spawn(async move {
         process_until_quit!(sub, quit, msg, {
             match serde_json::from_slice::<InterfaceLinkDefinition>(&msg.payload) {
@@ -268,8 +305,21 @@ async fn subscribe_link_put(
                         error!(%err, "failed to send link put request");
                         continue;
                     }
-                    if let Err(err) = rx.await {
-                        error!(%err, "failed to await link_put");
+                    match rx.await.as_ref().map(serde_json::to_vec) {
+                        Err(err) => {
+                            error!(%err, "failed to await link_put");
+                        }
+                        Ok(Ok(t)) => {
+                            // Only reply if the sender asked for the outcome of the link put
+                            if let Some(reply_to) = msg.reply {
+                                if let Err(err) = nats.publish(reply_to, t.into()).await {
+                                    error!(%err, "failed sending link put response");
+                                }
+                            }
+                        }
+                        Ok(Err(err)) => {
+                            error!(%err, "failed serializing LinkPutResponse");
+                        }
                     }
                 }
                 Err(err) => {
@@ -278,7 +328,7 @@ async fn subscribe_link_put(
             }
         });
     });
-    Ok(link_put_rx)
+    Ok(())
 }
 
 async fn subscribe_link_del(
@@ -286,11 +336,11 @@ async fn subscribe_link_del(
     mut quit: broadcast::Receiver<()>,
     lattice: &str,
     provider_key: &str,
-) -> ProviderInitResult<mpsc::Receiver<(InterfaceLinkDefinition, oneshot::Sender<()>)>> {
+    link_del_tx: mpsc::Sender<(InterfaceLinkDefinition, oneshot::Sender<()>)>,
+) -> ProviderInitResult<()> {
     let subject = link_del_subject(lattice, provider_key).to_subject();
     debug!(%subject, "subscribing for link del");
     let mut sub = nats.subscribe(subject.clone()).await?;
-    let (link_del_tx, link_del_rx) = mpsc::channel(1);
     let span = tracing::trace_span!("subscribe_link_del", %subject);
     spawn(
         async move {
@@ -309,14 +359,104 @@ async fn subscribe_link_del(
         }
         .instrument(span),
     );
-    Ok(link_del_rx)
+    Ok(())
+}
+
+async fn subscribe_config_update(
+    nats: Arc<async_nats::Client>,
+    mut quit: broadcast::Receiver<()>,
+    lattice: &str,
+    provider_key: &str,
+    config_update_tx: mpsc::Sender<(HashMap<String, String>, oneshot::Sender<()>)>,
+) -> ProviderInitResult<()> {
+    let mut sub = nats
+        .subscribe(provider_config_update_subject(lattice, provider_key))
+        .await?;
+    spawn(
+        async move {
+            process_until_quit!(sub, quit, msg, {
+                match serde_json::from_slice::<HashMap<String, String>>(&msg.payload) {
+                    Ok(config) => {
+                        let (tx, rx) = oneshot::channel();
+                        if let Err(err) = config_update_tx.send((config, tx)).await {
+                            error!(%err, "failed to send config update");
+                            continue;
+                        }
+                        if let Err(err) = rx.await {
+                            error!(%err, "failed to await config update");
+                        }
+                    }
+                    Err(err) => {
+                        error!(%err, "received invalid config data on message");
+                    }
+                }
+            });
+        }
+        .instrument(tracing::debug_span!("subscribe_config_update")),
+    );
+    Ok(())
+}
+
+/// Handle log filter changes directly, as they concern the SDK-configured subscriber rather than
+/// the provider
+async fn subscribe_log_filter(
+    nats: Arc<async_nats::Client>,
+    mut quit: broadcast::Receiver<()>,
+    lattice: &str,
+    provider_key: &str,
+) -> ProviderInitResult<()> {
+    let mut sub = nats
+        .subscribe(log_filter_subject(lattice, provider_key))
+        .await?;
+    spawn(
+        async move {
+            process_until_quit!(sub, quit, msg, {
+                let res = match core::str::from_utf8(&msg.payload) {
+                    Ok(directives) => wasmcloud_tracing::set_log_filter(directives.trim())
+                        .map(|()| format!("log filter set to `{}`", directives.trim())),
+                    Err(err) => Err(anyhow::anyhow!(err).context("log filter is not valid UTF-8")),
+                };
+                let reply = match res {
+                    Ok(reply) => {
+                        info!(%reply, "updated log filter");
+                        reply
+                    }
+                    Err(err) => {
+                        error!(%err, "failed to update log filter");
+                        format!("{err:#}")
+                    }
+                };
+                if let Some(reply_to) = msg.reply {
+                    if let Err(err) = nats.publish(reply_to, reply.into()).await {
+                        error!(%err, "failed sending log filter response");
+                    }
+                }
+            });
+        }
+        .instrument(tracing::debug_span!("subscribe_log_filter")),
+    );
+    Ok(())
 }
 
 pub(crate) struct ProviderCommandReceivers {
     pub health: mpsc::Receiver<(HealthCheckRequest, oneshot::Sender<HealthCheckResponse>)>,
-    pub shutdown: mpsc::Receiver<oneshot::Sender<()>>,
-    pub link_put: mpsc::Receiver<(InterfaceLinkDefinition, oneshot::Sender<()>)>,
+    pub shutdown: mpsc::Receiver<(ShutdownReason, oneshot::Sender<()>)>,
+    pub link_put: mpsc::Receiver<(InterfaceLinkDefinition, oneshot::Sender<LinkPutResponse>)>,
     pub link_del: mpsc::Receiver<(InterfaceLinkDefinition, oneshot::Sender<()>)>,
+    pub config_update: mpsc::Receiver<(HashMap<String, String>, oneshot::Sender<()>)>,
+    pub reconnect: mpsc::Receiver<()>,
+}
+
+/// Senders of the commands in [`ProviderCommandReceivers`], shared by the subscriptions of every
+/// lattice connection the provider makes
+#[derive(Clone)]
+pub(crate) struct ProviderCommandSenders {
+    pub health: mpsc::Sender<(HealthCheckRequest, oneshot::Sender<HealthCheckResponse>)>,
+    pub shutdown: mpsc::Sender<(ShutdownReason, oneshot::Sender<()>)>,
+    pub link_put: mpsc::Sender<(InterfaceLinkDefinition, oneshot::Sender<LinkPutResponse>)>,
+    pub link_del: mpsc::Sender<(InterfaceLinkDefinition, oneshot::Sender<()>)>,
+    pub config_update: mpsc::Sender<(HashMap<String, String>, oneshot::Sender<()>)>,
+    pub reconnect: mpsc::Sender<()>,
 }
 
 /// State of provider initialization
@@ -329,53 +469,56 @@ pub(crate) struct ProviderInitState {
     pub provider_key: String,
     pub link_definitions: Vec<InterfaceLinkDefinition>,
     pub commands: ProviderCommandReceivers,
+    pub command_senders: ProviderCommandSenders,
+    /// Stops the subscriptions to host commands on `nats`, once the connection is replaced
+    pub connection_stop: broadcast::Sender<()>,
     pub config: HashMap<String, String>,
 }
 
-#[instrument]
-async fn init_provider(name: &str) -> ProviderInitResult<ProviderInitState> {
+/// Connect to the lattice as configured by the host, notifying `reconnect` whenever a connection
+/// that was lost is re-established
+async fn connect_to_lattice(
+    host_data: &HostData,
+    reconnect: mpsc::Sender<()>,
+) -> ProviderInitResult<async_nats::Client> {
     let HostData {
-        host_id,
-        lattice_rpc_prefix,
         lattice_rpc_user_jwt,
         lattice_rpc_user_seed,
         lattice_rpc_url,
-        provider_key,
-        env_values: _,
-        cluster_issuers: _,
-        instance_id,
-        link_definitions,
-        config,
-        default_rpc_timeout_ms: _,
-        structured_logging,
-        log_level,
-        otel_config,
-        link_name: _link_name,
-    } = spawn_blocking(load_host_data).await.map_err(|e| {
-        ProviderInitError::Initialization(format!("failed to load host data: {e}"))
-    })??;
-
-    if let Err(err) = wasmcloud_tracing::configure_observability(
-        name,
-        otel_config,
-        *structured_logging,
-        log_level.as_ref(),
-    ) {
-        error!(?err, "failed to configure tracing");
-    }
-
-    let (quit_tx, quit_rx) = broadcast::channel(1);
-
-    info!(
-        "Starting capability provider {provider_key} instance {instance_id} with nats url {lattice_rpc_url}"
-    );
+        lattice_rpc_credsfile,
+        lattice_rpc_tls,
+        lattice_rpc_tls_ca_files,
+        lattice_rpc_tls_client_cert,
+        lattice_rpc_tls_client_key,
+        lattice_rpc_max_reconnects,
+        lattice_rpc_reconnect_delay_max_ms,
+        ..
+    } = host_data;
 
     let nats_addr = if !lattice_rpc_url.is_empty() {
         lattice_rpc_url.as_str()
     } else {
         DEFAULT_NATS_ADDR
     };
-    let nats = with_connection_event_logging(
+    // The client fails over between all given servers, restoring subscriptions on reconnect
+    let nats_addrs: Vec<&str> = nats_addr.split(',').map(str::trim).collect();
+    for addr in &nats_addrs {
+        if let Err(e) = addr.parse::<async_nats::ServerAddr>() {
+            return Err(ProviderInitError::Initialization(format!(
+                "invalid NATS server URL `{addr}`: {e}"
+            )));
+        }
+    }
+    let connect_options = if let Some(credsfile) = lattice_rpc_credsfile {
+        async_nats::ConnectOptions::with_credentials_file(credsfile.clone())
+            .await
+            .map_err(|e| {
+                ProviderInitError::Initialization(format!(
+                    "failed to read NATS credentials file `{}`: {e}",
+                    credsfile.display()
+                ))
+            })?
+    } else {
         match (lattice_rpc_user_jwt.trim(), lattice_rpc_user_seed.trim()) {
             ("", "") => async_nats::ConnectOptions::default(),
             (rpc_jwt, rpc_seed) => {
@@ -386,38 +529,198 @@ async fn init_provider(name: &str) -> ProviderInitResult<ProviderInitState> {
                     async move { key_pair.sign(&nonce).map_err(async_nats::AuthError::new) }
                 })
             }
-        },
-    )
-    .connect(nats_addr)
-    .await?;
-    let nats = Arc::new(nats);
-    let (health, shutdown, link_put, link_del) = try_join!(
+        }
+    };
+    let connect_options = lattice_rpc_tls_ca_files.iter().fold(
+        connect_options.require_tls(*lattice_rpc_tls),
+        |opts, ca_file| opts.add_root_certificates(ca_file.clone()),
+    );
+    let connect_options = match (lattice_rpc_tls_client_cert, lattice_rpc_tls_client_key) {
+        (Some(cert), Some(key)) => {
+            connect_options.add_client_certificate(cert.clone(), key.clone())
+        }
+        (None, None) => connect_options,
+        _ => {
+            return Err(ProviderInitError::Initialization(
+                "NATS client certificate and key must be set together".to_string(),
+            ))
+        }
+    };
+    let connect_options = if let Some(max_reconnects) = lattice_rpc_max_reconnects {
+        connect_options.max_reconnects(*max_reconnects)
+    } else {
+        connect_options
+    };
+    let connect_options = if let Some(max_delay_ms) = lattice_rpc_reconnect_delay_max_ms {
+        let max_delay = Duration::from_millis(*max_delay_ms);
+        connect_options.reconnect_delay_callback(move |attempts| {
+            // Exponential backoff starting at 100ms, capped at the configured maximum
+            Duration::from_millis(100u64.saturating_mul(1 << attempts.min(16))).min(max_delay)
+        })
+    } else {
+        connect_options
+    };
+
+    // Notify the provider only when a connection that was lost is re-established, not on the
+    // initial connection
+    let disconnected = Arc::new(AtomicBool::new(false));
+    let connect_options = connect_options.event_callback(move |event| {
+        let reconnect = reconnect.clone();
+        let disconnected = Arc::clone(&disconnected);
+        async move {
+            log_connection_event(&event);
+            match event {
+                async_nats::Event::Disconnected => disconnected.store(true, Ordering::Relaxed),
+                async_nats::Event::Connected if disconnected.swap(false, Ordering::Relaxed) => {
+                    // A pending notification already covers this reconnect
+                    let _ = reconnect.try_send(());
+                }
+                _ => {}
+            }
+        }
+    });
+    Ok(connect_options.connect(nats_addrs.as_slice()).await?)
+}
+
+/// Returns a channel that is signaled when the subscriptions of a lattice connection should stop,
+/// which is when the provider quits or the connection is replaced
+fn connection_stop_channel(mut quit: broadcast::Receiver<()>) -> broadcast::Sender<()> {
+    let (stop_tx, mut stop_rx) = broadcast::channel(1);
+    spawn({
+        let stop_tx = stop_tx.clone();
+        async move {
+            select! {
+                _ = quit.recv() => {
+                    let _ = stop_tx.send(());
+                }
+                _ = stop_rx.recv() => {}
+            }
+        }
+    });
+    stop_tx
+}
+
+/// Subscribe on `nats` to the commands the host sends to the provider, until `stop` is signaled
+async fn subscribe_commands(
+    nats: &Arc<async_nats::Client>,
+    quit_tx: &broadcast::Sender<()>,
+    stop: &broadcast::Sender<()>,
+    host_data: &'static HostData,
+    commands: &ProviderCommandSenders,
+) -> ProviderInitResult<()> {
+    let HostData {
+        host_id,
+        lattice_rpc_prefix,
+        provider_key,
+        ..
+    } = host_data;
+    try_join!(
         subscribe_health(
-            Arc::clone(&nats),
-            quit_tx.subscribe(),
+            Arc::clone(nats),
+            stop.subscribe(),
             lattice_rpc_prefix,
-            provider_key
+            provider_key,
+            commands.health.clone(),
         ),
         subscribe_shutdown(
-            Arc::clone(&nats),
+            Arc::clone(nats),
             quit_tx.clone(),
+            stop.subscribe(),
             lattice_rpc_prefix,
             provider_key,
-            host_id
+            host_id,
+            commands.shutdown.clone(),
         ),
         subscribe_link_put(
-            Arc::clone(&nats),
-            quit_tx.subscribe(),
+            Arc::clone(nats),
+            stop.subscribe(),
             lattice_rpc_prefix,
             provider_key,
+            commands.link_put.clone(),
         ),
         subscribe_link_del(
-            Arc::clone(&nats),
-            quit_tx.subscribe(),
+            Arc::clone(nats),
+            stop.subscribe(),
+            lattice_rpc_prefix,
+            provider_key,
+            commands.link_del.clone(),
+        ),
+        subscribe_config_update(
+            Arc::clone(nats),
+            stop.subscribe(),
+            lattice_rpc_prefix,
+            provider_key,
+            commands.config_update.clone(),
+        ),
+        subscribe_log_filter(
+            Arc::clone(nats),
+            stop.subscribe(),
             lattice_rpc_prefix,
             provider_key,
         ),
     )?;
+    Ok(())
+}
+
+#[instrument]
+async fn init_provider(name: &str) -> ProviderInitResult<ProviderInitState> {
+    let host_data = spawn_blocking(load_host_data).await.map_err(|e| {
+        ProviderInitError::Initialization(format!("failed to load host data: {e}"))
+    })??;
+    let HostData {
+        host_id,
+        lattice_rpc_prefix,
+        lattice_rpc_url,
+        provider_key,
+        instance_id,
+        link_definitions,
+        config,
+        structured_logging,
+        log_level,
+        otel_config,
+        ..
+    } = host_data;
+
+    if let Err(err) = wasmcloud_tracing::configure_observability(
+        name,
+        otel_config,
+        *structured_logging,
+        log_level.as_ref(),
+    ) {
+        error!(?err, "failed to configure tracing");
+    }
+
+    let (quit_tx, quit_rx) = broadcast::channel(1);
+
+    info!(
+        "Starting capability provider {provider_key} instance {instance_id} with nats url {lattice_rpc_url}"
+    );
+
+    let (health_tx, health) = mpsc::channel(1);
+    let (shutdown_tx, shutdown) = mpsc::channel(1);
+    let (link_put_tx, link_put) = mpsc::channel(1);
+    let (link_del_tx, link_del) = mpsc::channel(1);
+    let (config_update_tx, config_update) = mpsc::channel(1);
+    let (reconnect_tx, reconnect) = mpsc::channel(1);
+    let command_senders = ProviderCommandSenders {
+        health: health_tx,
+        shutdown: shutdown_tx,
+        link_put: link_put_tx,
+        link_del: link_del_tx,
+        config_update: config_update_tx,
+        reconnect: reconnect_tx,
+    };
+    let nats = connect_to_lattice(host_data, command_senders.reconnect.clone()).await?;
+    let nats = Arc::new(nats);
+    let connection_stop = connection_stop_channel(quit_tx.subscribe());
+    subscribe_commands(
+        &nats,
+        &quit_tx,
+        &connection_stop,
+        host_data,
+        &command_senders,
+    )
+    .await?;
     Ok(ProviderInitState {
         nats,
         quit_rx,
@@ -432,11 +735,17 @@ async fn init_provider(name: &str) -> ProviderInitResult<ProviderInitState> {
             shutdown,
             link_put,
             link_del,
+            config_update,
+            reconnect,
         },
+        command_senders,
+        connection_stop,
     })
 }
 
 /// Appropriately receive a link (depending on if it's source/target) for a provider
+///
+/// Returns an error if the provider rejected the link, in which case it is not stored
 async fn receive_link_for_provider<P>(
     provider: &P,
     connection: &ProviderConnection,
@@ -445,35 +754,40 @@ async fn receive_link_for_provider<P>(
 where
     P: Provider,
 {
-    match if ld.source_id == connection.provider_id {
-        provider
-            .receive_link_config_as_source(LinkConfig {
-                source_id: &ld.source_id,
-                target_id: &ld.target,
-                link_name: &ld.name,
-                config: &ld.source_config,
-                wit_metadata: (&ld.wit_namespace, &ld.wit_package, &ld.interfaces),
-            })
-            .await
-    } else if ld.target == connection.provider_id {
-        provider
-            .receive_link_config_as_target(LinkConfig {
-                source_id: &ld.source_id,
-                target_id: &ld.target,
-                link_name: &ld.name,
-                config: &ld.target_config,
-                wit_metadata: (&ld.wit_namespace, &ld.wit_package, &ld.interfaces),
-            })
+    let is_source = ld.source_id == connection.provider_id;
+    if !is_source && ld.target != connection.provider_id {
+        bail!("received link put where provider was neither source nor target");
+    }
+    let config = if is_source {
+        &ld.source_config
+    } else {
+        &ld.target_config
+    };
+    let secrets = match &connection.secrets_resolver {
+        Some(resolver) => resolve_secret_references(config, resolver)
             .await
+            .context("failed to resolve secrets referenced by link")?,
+        None => HashMap::new(),
+    };
+    let link = LinkConfig {
+        source_id: &ld.source_id,
+        target_id: &ld.target,
+        link_name: &ld.name,
+        config,
+        secrets: &secrets,
+        wit_metadata: (&ld.wit_namespace, &ld.wit_package, &ld.interfaces),
+    };
+    match if is_source {
+        provider.receive_link_config_as_source(link).await
     } else {
-        bail!("received link put where provider was neither source nor target");
+        provider.receive_link_config_as_target(link).await
     } {
-        Ok(()) => connection.put_link(ld).await,
-        Err(e) => {
-            warn!(error = %e, "receiving link failed");
+        Ok(()) => {
+            connection.put_link(ld).await;
+            Ok(())
         }
-    };
-    Ok(())
+        Err(e) => Err(e.context("provider rejected link")),
+    }
 }
 
 async fn delete_link_for_provider<P>(
@@ -484,12 +798,18 @@ async fn delete_link_for_provider<P>(
 where
     P: Provider,
 {
+    let info = LinkDeleteInfo {
+        source_id: &ld.source_id,
+        target_id: &ld.target,
+        link_name: &ld.name,
+        wit_metadata: (&ld.wit_namespace, &ld.wit_package, &ld.interfaces),
+    };
     if ld.source_id == connection.provider_id {
-        if let Err(e) = provider.delete_link_as_source(&ld.target).await {
+        if let Err(e) = provider.delete_link_as_source(info).await {
             error!(error = %e, target = &ld.target, "failed to delete link to component");
         }
     } else if ld.target == connection.provider_id {
-        if let Err(e) = provider.delete_link_as_target(&ld.source_id).await {
+        if let Err(e) = provider.delete_link_as_target(info).await {
             error!(error = %e, source = &ld.source_id, "failed to delete link from component");
         }
     }
@@ -497,6 +817,45 @@ where
     Ok(())
 }
 
+/// Reconcile the links of the provider with the current links the host has for it, receiving links
+/// that were put and deleting links that were deleted while the provider could not receive them
+async fn resync_links<P>(provider: &P, connection: &ProviderConnection) -> Result<()>
+where
+    P: Provider,
+{
+    let res = connection
+        .nats()
+        .request(
+            link_get_subject(&connection.lattice, &connection.provider_id),
+            Bytes::new(),
+        )
+        .await
+        .context("failed to request links from host")?;
+    let links: Vec<InterfaceLinkDefinition> =
+        serde_json::from_slice(&res.payload).context("failed to decode links")?;
+    // Links are compared as a whole (source, target, name, WIT interfaces and config), so links
+    // that changed are deleted here and received again with their current definition below
+    let current = connection.links().await;
+    for ld in current {
+        if !links.contains(&ld) {
+            delete_link_for_provider(provider, connection, ld).await?;
+        }
+    }
+    for ld in links {
+        if connection
+            .get_link(&ld.source_id, &ld.target)
+            .await
+            .as_ref()
+            != Some(&ld)
+        {
+            if let Err(e) = receive_link_for_provider(provider, connection, ld).await {
+                error!(error = %e, "failed to receive link during link resync");
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Handle provider commands in a loop.
 async fn handle_provider_commands(
     provider: impl Provider,
@@ -508,6 +867,8 @@ async fn handle_provider_commands(
         mut shutdown,
         mut link_put,
         mut link_del,
+        mut config_update,
+        mut reconnect,
     }: ProviderCommandReceivers,
 ) {
     loop {
@@ -520,13 +881,19 @@ async fn handle_provider_commands(
             }
             req = health.recv() => {
                 if let Some((req, tx)) = req {
-                    let res = match provider.health_request(&req).await {
+                    let mut res = match provider.health_request(&req).await {
                         Ok(v) => v,
                         Err(e) => {
                             error!(error = %e, "provider health request failed");
                             return;
                         }
                     };
+                    // Details reported by the provider itself take precedence
+                    if let Some(info) = provider.build_info() {
+                        for (key, value) in info.details() {
+                            res.details.entry(key).or_insert(value);
+                        }
+                    }
                     if tx.send(res).is_err() {
                         error!("failed to send health check response");
                     }
@@ -542,8 +909,10 @@ async fn handle_provider_commands(
                 };
             }
             req = shutdown.recv() => {
-                if let Some(tx) = req {
-                    if let Err(e) = provider.shutdown().await {
+                if let Some((reason, tx)) = req {
+                    // Let scheduled tasks finish their current run before the provider tears down
+                    connection.stop_scheduled_tasks().await;
+                    if let Err(e) = provider.shutdown_with_reason(reason).await {
                         error!(error = %e, "failed to shutdown provider");
                     }
                     if tx.send(()).is_err() {
@@ -563,15 +932,20 @@ async fn handle_provider_commands(
             req = link_put.recv() => {
                 if let Some((ld, tx)) = req {
                     // If the link has already been put, return early
-                    if connection.is_linked(&ld.source_id, &ld.target).await {
+                    let res = if connection.is_linked(&ld.source_id, &ld.target).await {
                         warn!(source = &ld.source_id, target = &ld.target, "Ignoring duplicate link put");
+                        LinkPutResponse { accepted: true, message: None }
                     } else {
                         info!("Linking component with provider");
-                        if let Err(e) = receive_link_for_provider(&provider, connection, ld).await {
-                            error!(error = %e, "failed to receive link for provider");
+                        match receive_link_for_provider(&provider, connection, ld).await {
+                            Ok(()) => LinkPutResponse { accepted: true, message: None },
+                            Err(e) => {
+                                error!(error = %e, "failed to receive link for provider");
+                                LinkPutResponse { accepted: false, message: Some(format!("{e:#}")) }
+                            }
                         }
-                    }
-                    if tx.send(()).is_err() {
+                    };
+                    if tx.send(res).is_err() {
                         error!("failed to send link put response");
                     }
                 } else {
@@ -606,6 +980,47 @@ async fn handle_provider_commands(
                     return
                 };
             }
+            req = config_update.recv() => {
+                if let Some((config, tx)) = req {
+                    // The stored config is only replaced if the provider accepted the update
+                    match provider.receive_config_update(&config).await {
+                        Ok(()) => connection.update_config(config).await,
+                        Err(e) => error!(error = %e, "provider failed to apply config update"),
+                    }
+                    if tx.send(()).is_err() {
+                        error!("failed to send config update response");
+                    }
+                } else {
+                    error!("failed to handle config update, shutdown");
+                    if let Err(e) = provider.shutdown().await {
+                        error!(error = %e, "failed to shutdown provider");
+                    }
+                    if quit_tx.send(()).is_err() {
+                        error!("failed to send quit");
+                    };
+                    return
+                };
+            }
+            req = reconnect.recv() => {
+                if req.is_some() {
+                    // Links put or deleted while disconnected were never delivered
+                    if let Err(e) = resync_links(&provider, connection).await {
+                        error!(error = %e, "failed to resync links after reconnect");
+                    }
+                    if let Err(e) = provider.on_reconnect().await {
+                        error!(error = %e, "provider failed to handle reconnect");
+                    }
+                } else {
+                    error!("failed to handle reconnect, shutdown");
+                    if let Err(e) = provider.shutdown().await {
+                        error!(error = %e, "failed to shutdown provider");
+                    }
+                    if quit_tx.send(()).is_err() {
+                        error!("failed to send quit");
+                    };
+                    return
+                };
+            }
         }
     }
 }
@@ -615,6 +1030,31 @@ async fn handle_provider_commands(
 pub async fn run_provider(
     provider: impl Provider,
     friendly_name: &str,
+) -> ProviderInitResult<impl Future<Output = ()>> {
+    start_provider_handler(provider, friendly_name, None).await
+}
+
+/// Like [`run_provider`], but resolves the secrets referenced in the config of every link with
+/// `resolver` before the provider receives the link (see [`crate::secrets`])
+///
+/// Links referencing secrets that do not exist or cannot be resolved are rejected.
+pub async fn run_provider_with_secrets(
+    provider: impl Provider,
+    friendly_name: &str,
+    resolver: impl SecretsResolver + 'static,
+) -> ProviderInitResult<impl Future<Output = ()>> {
+    start_provider_handler(
+        provider,
+        friendly_name,
+        Some(ProviderSecretsResolver::new(resolver)),
+    )
+    .await
+}
+
+async fn start_provider_handler(
+    provider: impl Provider,
+    friendly_name: &str,
+    secrets_resolver: Option<ProviderSecretsResolver>,
 ) -> ProviderInitResult<impl Future<Output = ()>> {
     let init_state = init_provider(friendly_name).await?;
 
@@ -634,21 +1074,46 @@ pub async fn run_provider(
         provider_key,
         link_definitions,
         commands,
+        command_senders,
+        connection_stop,
         config,
     } = init_state;
 
-    let connection = ProviderConnection::new(
+    let mut connection = ProviderConnection::new(
         Arc::clone(&nats),
         provider_key,
         lattice_rpc_prefix.clone(),
         host_id,
         config,
     )?;
+    connection.secrets_resolver = secrets_resolver;
+    let host_data = load_host_data()?;
+    if let Some(delay_ms) = host_data.provider_shutdown_delay_ms {
+        connection.shutdown_delay = Duration::from_millis(delay_ms);
+    }
     CONNECTION.set(connection).map_err(|_| {
         ProviderInitError::Initialization("Provider connection was already initialized".to_string())
     })?;
     let connection = get_connection();
 
+    if let (Some(cert), Some(key)) = (
+        &host_data.lattice_rpc_tls_client_cert,
+        &host_data.lattice_rpc_tls_client_key,
+    ) {
+        spawn(
+            rotate_client_certificate(
+                host_data,
+                cert,
+                key,
+                connection,
+                quit_tx.clone(),
+                connection_stop,
+                command_senders,
+            )
+            .instrument(tracing::debug_span!("rotate_client_certificate")),
+        );
+    }
+
     // Provide all links to the provider at startup to establish the initial state
     for ld in link_definitions {
         if let Err(e) = receive_link_for_provider(&provider, connection, ld).await {
@@ -665,6 +1130,104 @@ pub async fn run_provider(
     ))
 }
 
+/// Interval at which the client certificate and key files are checked for changes
+const CLIENT_CERTIFICATE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Modification times of the client certificate and key files, if both can be read
+async fn client_certificate_modified(cert: &Path, key: &Path) -> Option<(SystemTime, SystemTime)> {
+    let cert = tokio::fs::metadata(cert).await.and_then(|md| md.modified());
+    let key = tokio::fs::metadata(key).await.and_then(|md| md.modified());
+    Some((cert.ok()?, key.ok()?))
+}
+
+/// Stream of `SIGHUP` signals received by the process, which request the client certificate to be
+/// reloaded
+#[cfg(unix)]
+fn hangups() -> BoxStream<'static, ()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    match signal(SignalKind::hangup()) {
+        Ok(hangup) => stream::unfold(hangup, |mut hangup| async move {
+            hangup.recv().await.map(|()| ((), hangup))
+        })
+        .boxed(),
+        Err(err) => {
+            warn!(%err, "failed to listen for SIGHUP, reloading the client certificate on file changes only");
+            stream::pending().boxed()
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn hangups() -> BoxStream<'static, ()> {
+    stream::pending().boxed()
+}
+
+/// Reconnect to the lattice with the current client certificate and key whenever their files
+/// change or the process receives `SIGHUP`, until the provider quits
+///
+/// The NATS client cannot be made to reconnect on demand, so a new connection is established and
+/// the subscriptions to host commands are moved to it. The provider is then notified as after any
+/// other reconnect, which also resynchronizes its links.
+async fn rotate_client_certificate(
+    host_data: &'static HostData,
+    cert: &Path,
+    key: &Path,
+    connection: &ProviderConnection,
+    quit_tx: broadcast::Sender<()>,
+    mut connection_stop: broadcast::Sender<()>,
+    commands: ProviderCommandSenders,
+) {
+    let mut quit = quit_tx.subscribe();
+    let mut hangups = hangups();
+    let mut modified = client_certificate_modified(cert, key).await;
+    let mut poll = interval_at(
+        Instant::now() + CLIENT_CERTIFICATE_POLL_INTERVAL,
+        CLIENT_CERTIFICATE_POLL_INTERVAL,
+    );
+    poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        let current = select! {
+            _ = quit.recv() => return,
+            _ = poll.tick() => {
+                let current = client_certificate_modified(cert, key).await;
+                // Wait for both files to be readable again if they are being replaced
+                if current.is_none() || current == modified {
+                    continue;
+                }
+                info!("client certificate changed, reconnecting to the lattice");
+                current
+            }
+            Some(()) = hangups.next() => {
+                info!("received SIGHUP, reconnecting to the lattice");
+                client_certificate_modified(cert, key).await
+            }
+        };
+        let nats = match connect_to_lattice(host_data, commands.reconnect.clone()).await {
+            Ok(nats) => Arc::new(nats),
+            Err(err) => {
+                // Retried on the next check, since the modification times are left unchanged
+                error!(%err, "failed to reconnect to the lattice with the current client certificate");
+                continue;
+            }
+        };
+        let stop = connection_stop_channel(quit_tx.subscribe());
+        if let Err(err) = subscribe_commands(&nats, &quit_tx, &stop, host_data, &commands).await {
+            error!(%err, "failed to subscribe to host commands on the new lattice connection");
+            let _ = stop.send(());
+            continue;
+        }
+        connection.nats.send_replace(nats);
+        let _ = mem::replace(&mut connection_stop, stop).send(());
+        modified = current;
+        // Commands sent while the subscriptions moved may have been missed
+        let _ = commands.reconnect.try_send(());
+    }
+}
+
+/// Time hosts wait for providers to shut down before terminating them, unless configured otherwise
+const DEFAULT_SHUTDOWN_DELAY: Duration = Duration::from_millis(300);
+
 /// Source ID for a link
 type SourceId = String;
 
@@ -677,17 +1240,26 @@ pub struct ProviderConnection {
     /// target of the link. Indexed by the component ID of the source
     target_links: Arc<RwLock<HashMap<SourceId, InterfaceLinkDefinition>>>,
 
-    /// NATS client used for performing RPCs
-    nats: Arc<async_nats::Client>,
+    /// NATS client used for performing RPCs, replaced when the client certificate is rotated
+    nats: Arc<watch::Sender<Arc<async_nats::Client>>>,
 
     /// Lattice name
     lattice: String,
     host_id: String,
     provider_id: String,
 
-    // TODO: Reference this field to get static config
-    #[allow(unused)]
-    config: HashMap<String, String>,
+    /// Merged named configuration of the provider, updated as the host redelivers changes
+    config: Arc<RwLock<HashMap<String, String>>>,
+
+    /// Tasks scheduled with [`ProviderConnection::schedule_interval`] and
+    /// [`ProviderConnection::schedule`], stopped on shutdown
+    scheduled_tasks: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    stop_scheduled_tasks: Arc<watch::Sender<bool>>,
+    /// Time the host waits for the provider to shut down before terminating it
+    shutdown_delay: Duration,
+
+    /// Resolver of secrets referenced in link config, if the provider was started with one
+    secrets_resolver: Option<ProviderSecretsResolver>,
 }
 
 impl fmt::Debug for ProviderConnection {
@@ -730,14 +1302,23 @@ impl ProviderConnection {
         Ok(ProviderConnection {
             source_links: Arc::default(),
             target_links: Arc::default(),
-            nats,
+            nats: Arc::new(watch::channel(nats).0),
             lattice,
             host_id,
             provider_id,
-            config,
+            config: Arc::new(RwLock::new(config)),
+            scheduled_tasks: Arc::default(),
+            stop_scheduled_tasks: Arc::new(watch::channel(false).0),
+            shutdown_delay: DEFAULT_SHUTDOWN_DELAY,
+            secrets_resolver: None,
         })
     }
 
+    /// NATS client of the current lattice connection
+    fn nats(&self) -> Arc<async_nats::Client> {
+        Arc::clone(&self.nats.borrow())
+    }
+
     /// Retrieve a wRPC client that can be used based on the NATS client of this connection
     ///
     /// # Arguments
@@ -772,7 +1353,7 @@ impl ProviderConnection {
         hmap.insert("source-id", self.provider_id.as_str());
         hmap.insert("target-id", target);
         WrpcClient(wasmcloud_core::wrpc::Client::new(
-            Arc::clone(&self.nats),
+            self.nats(),
             &self.lattice,
             target,
             hmap,
@@ -780,12 +1361,485 @@ impl ProviderConnection {
         ))
     }
 
+    /// Retrieve a wRPC client whose invocations continue the trace of an inbound invocation,
+    /// by copying the trace context carried in `context` onto the outgoing headers.
+    ///
+    /// This is useful when an outgoing call is made outside of the span that handled the
+    /// inbound invocation (e.g. from a spawned task). Without a `context`, the trace context of the
+    /// current span is used when the `otel` feature is enabled.
+    ///
+    /// The trace context is captured when the client is created, so create a client for each
+    /// inbound invocation rather than reusing it across invocations.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Target ID to which invocations will be sent
+    /// * `context` - Context of the inbound invocation, if any
+    #[must_use]
+    pub fn get_wrpc_client_for_context(
+        &self,
+        target: &str,
+        context: Option<&Context>,
+    ) -> WrpcClient {
+        let headers = match context {
+            Some(Context { tracing, .. }) => Some(
+                tracing
+                    .iter()
+                    .filter(|(k, _)| TRACE_CONTEXT_HEADER_NAMES.contains(&k.as_str()))
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            ),
+            #[cfg(feature = "otel")]
+            None => Some(
+                TraceContextInjector::default_with_span()
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            ),
+            #[cfg(not(feature = "otel"))]
+            None => None,
+        };
+        self.get_wrpc_client_custom(target, headers, None)
+    }
+
+    /// Retrieve a wRPC client for the target of a link where this provider is the source,
+    /// resolved by link name and interface rather than by a hardcoded target ID.
+    ///
+    /// Returns `None` if the provider has no such link.
+    ///
+    /// # Arguments
+    ///
+    /// * `link_name` - Name of the link (`default` if none was given when linking)
+    /// * `wit_namespace` - WIT namespace of the linked interface, e.g. `wasmcloud`
+    /// * `wit_package` - WIT package of the linked interface, e.g. `messaging`
+    /// * `wit_interface` - WIT interface that the target exports, e.g. `handler`
+    pub async fn get_wrpc_client_for_link(
+        &self,
+        link_name: &str,
+        wit_namespace: &str,
+        wit_package: &str,
+        wit_interface: &str,
+    ) -> Option<WrpcClient> {
+        let source_links = self.source_links.read().await;
+        let ld = source_links.values().find(|ld| {
+            ld.name == link_name
+                && ld.wit_namespace == wit_namespace
+                && ld.wit_package == wit_package
+                && ld.interfaces.iter().any(|i| i == wit_interface)
+        })?;
+        Some(self.get_wrpc_client(&ld.target))
+    }
+
+    /// Retrieve a wRPC client for every target linked to this provider (where the provider is
+    /// the source of the link) on the given interface, paired with the target ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `wit_namespace` - WIT namespace of the linked interface, e.g. `wasmcloud`
+    /// * `wit_package` - WIT package of the linked interface, e.g. `messaging`
+    /// * `wit_interface` - WIT interface that the targets export, e.g. `handler`
+    pub async fn get_wrpc_clients_for_interface(
+        &self,
+        wit_namespace: &str,
+        wit_package: &str,
+        wit_interface: &str,
+    ) -> Vec<(LatticeTarget, WrpcClient)> {
+        self.source_links
+            .read()
+            .await
+            .values()
+            .filter(|ld| {
+                ld.wit_namespace == wit_namespace
+                    && ld.wit_package == wit_package
+                    && ld.interfaces.iter().any(|i| i == wit_interface)
+            })
+            .map(|ld| (ld.target.clone(), self.get_wrpc_client(&ld.target)))
+            .collect()
+    }
+
+    /// Concurrently invoke `f` with a wRPC client for every target linked to this provider on
+    /// the given interface, collecting the result for each target.
+    ///
+    /// This is intended for providers that deliver the same message to all linked components,
+    /// e.g. calling `wasmcloud:messaging/handler.handle-message` for each of them.
+    pub async fn invoke_all_linked<F, Fut, T>(
+        &self,
+        wit_namespace: &str,
+        wit_package: &str,
+        wit_interface: &str,
+        f: F,
+    ) -> Vec<(LatticeTarget, T)>
+    where
+        F: Fn(WrpcClient) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let clients = self
+            .get_wrpc_clients_for_interface(wit_namespace, wit_package, wit_interface)
+            .await;
+        join_all(clients.into_iter().map(|(target, client)| {
+            let fut = f(client);
+            async move { (target, fut.await) }
+        }))
+        .await
+    }
+
+    /// Subscribe to events published on the lattice, dispatching them to `handler`
+    ///
+    /// Events are handled one at a time on a spawned task, which runs until the returned handle
+    /// is aborted or the NATS connection is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if subscribing to lattice events failed
+    pub async fn subscribe_lattice_events(
+        &self,
+        handler: impl LatticeEventHandler,
+    ) -> Result<JoinHandle<()>> {
+        let sub = self
+            .nats()
+            .subscribe(lattice_events_subject(&self.lattice))
+            .await
+            .context("failed to subscribe to lattice events")?;
+        Ok(spawn(handle_lattice_events(sub, handler).instrument(
+            tracing::debug_span!("lattice_events", lattice = %self.lattice),
+        )))
+    }
+
+    /// Fetch the named config `name` from the lattice, or `None` if it does not exist
+    ///
+    /// Unlike [`config`](Self::config), this can read any named config, e.g. one shared by
+    /// several links, and always returns its latest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config could not be fetched from the host or is not valid
+    pub async fn get_named_config(&self, name: &str) -> Result<Option<HashMap<String, String>>> {
+        get_config(&self.nats(), &self.lattice, &self.provider_id, name).await
+    }
+
+    /// Watch the named config `name` on the lattice, calling `handler` with its current value, if
+    /// it exists, and then whenever it changes, with `None` when it is deleted
+    ///
+    /// The watch runs on a spawned task until the returned handle is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription to config changes could not be created
+    pub async fn watch_named_config<F, Fut>(&self, name: &str, handler: F) -> Result<JoinHandle<()>>
+    where
+        F: Fn(Option<HashMap<String, String>>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let changes = self
+            .nats()
+            .subscribe(named_config_changed_subject(&self.lattice, name))
+            .await
+            .with_context(|| format!("failed to subscribe to changes of config '{name}'"))?;
+        let span = tracing::debug_span!("watch_named_config", name);
+        let nats = self.nats();
+        let lattice = self.lattice.clone();
+        let provider_id = self.provider_id.clone();
+        let name = name.to_string();
+        Ok(spawn(
+            async move {
+                watch_config(&nats, &lattice, &provider_id, changes, name, handler).await;
+            }
+            .instrument(span),
+        ))
+    }
+
+    /// Open the JetStream key-value bucket `bucket` on the lattice connection, creating it with
+    /// default settings if it does not exist yet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bucket could not be accessed or created
+    pub async fn kv_bucket<T>(&self, bucket: &str) -> Result<KvBucket<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let jetstream = async_nats::jetstream::new((*self.nats()).clone());
+        let store = get_or_create_bucket(
+            &jetstream,
+            async_nats::jetstream::kv::Config {
+                bucket: bucket.to_string(),
+                ..Default::default()
+            },
+        )
+        .await?;
+        Ok(KvBucket::new(store))
+    }
+
+    /// Open the JetStream object store `bucket` on the lattice connection, creating it with
+    /// default settings if it does not exist yet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object store could not be accessed or created
+    pub async fn object_bucket(&self, bucket: &str) -> Result<ObjectBucket> {
+        let jetstream = async_nats::jetstream::new((*self.nats()).clone());
+        let store = get_or_create_object_store(
+            &jetstream,
+            async_nats::jetstream::object_store::Config {
+                bucket: bucket.to_string(),
+                ..Default::default()
+            },
+        )
+        .await?;
+        Ok(ObjectBucket::new(store))
+    }
+
+    /// Forward log records of the provider at `min_level` or above (e.g. [`tracing::Level::WARN`])
+    /// to the lattice, publishing them as JSON on
+    /// [`provider_log_subject`](wasmcloud_core::rpc::provider_log_subject)
+    ///
+    /// Records are buffered and published from a spawned task. When the buffer is full, e.g.
+    /// while disconnected from the lattice, further records are dropped rather than blocking
+    /// logging. Forwarding stops when the returned handle is aborted, or is replaced when this is
+    /// called again.
+    pub fn forward_logs(&self, min_level: tracing::Level) -> JoinHandle<()> {
+        let (tx, mut rx) = mpsc::channel(1024);
+        let forwarder = wasmcloud_tracing::set_log_forwarder(min_level, move |log| {
+            let _ = tx.try_send(log);
+        });
+        let nats = self.nats();
+        let subject = provider_log_subject(&self.lattice, &self.provider_id);
+        spawn(async move {
+            // Unregister the forwarder once the task stops, including when it is aborted
+            let _forwarder = forwarder;
+            while let Some(log) = rx.recv().await {
+                // Failures are not logged, since those logs would be forwarded again
+                if let Ok(payload) = serde_json::to_vec(&log) {
+                    let _ = nats.publish(subject.clone(), payload.into()).await;
+                }
+            }
+        })
+    }
+
+    /// Campaign to be the leader among the instances of this provider for the election `name`,
+    /// notifying `handler` whenever this instance gains or loses leadership
+    ///
+    /// The lease of the leader is stored in a JetStream key-value bucket of the lattice and expires
+    /// after `ttl` unless renewed, so another instance takes over within `ttl` if the leader
+    /// stops. The election runs on a spawned task until the returned handle is aborted.
+    ///
+    /// Leases with different TTLs are kept in different buckets, so all instances must campaign
+    /// for `name` with the same `ttl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the leader election bucket could not be accessed or created
+    pub async fn elect_leader(
+        &self,
+        name: &str,
+        ttl: Duration,
+        handler: impl LeadershipHandler,
+    ) -> Result<JoinHandle<()>> {
+        let store = leader_bucket(&self.nats(), &self.lattice, ttl).await?;
+        let key = format!("{}.{name}", self.provider_id);
+        Ok(spawn(
+            run_election(store, key.clone(), self.host_id.clone(), ttl, handler)
+                .instrument(tracing::debug_span!("leader_election", %key)),
+        ))
+    }
+
+    /// Subscribe to a custom NATS subject alongside the wRPC subjects served by the provider,
+    /// e.g. to receive webhooks, dispatching every message received on it to `handler`
+    ///
+    /// Messages are handled one at a time on a spawned task, which runs until the returned handle
+    /// is aborted or the NATS connection is closed. Replies, if any, can be published with the
+    /// NATS client passed to `handler`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if subscribing to `subject` failed
+    pub async fn subscribe<F, Fut>(
+        &self,
+        subject: impl ToSubject,
+        handler: F,
+    ) -> Result<JoinHandle<()>>
+    where
+        F: Fn(Arc<async_nats::Client>, async_nats::Message) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let subject = subject.to_subject();
+        let mut sub = self
+            .nats()
+            .subscribe(subject.clone())
+            .await
+            .with_context(|| format!("failed to subscribe to `{subject}`"))?;
+        let nats = self.nats();
+        Ok(spawn(
+            async move {
+                while let Some(msg) = sub.next().await {
+                    handler(Arc::clone(&nats), msg).await;
+                }
+            }
+            .instrument(tracing::debug_span!("custom_subscription", %subject)),
+        ))
+    }
+
+    /// Publish a JSON-encoded event of `topic` on the lattice, on behalf of this provider
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event could not be serialized or published
+    pub async fn publish_event(&self, topic: &str, event: &impl Serialize) -> Result<()> {
+        let payload = serde_json::to_vec(event).context("failed to serialize event")?;
+        self.nats()
+            .publish(
+                provider_event_subject(&self.lattice, &self.provider_id, topic),
+                payload.into(),
+            )
+            .await
+            .with_context(|| format!("failed to publish `{topic}` event"))
+    }
+
+    /// Subscribe to events of `topic` published on the lattice by the provider `provider_id`, or
+    /// by any provider if it is `None`, calling `handler` with the ID of the publishing provider
+    /// and the decoded event
+    ///
+    /// Events that fail to decode as `T` are logged and skipped. Events are handled one at a time
+    /// on a spawned task, which runs until the returned handle is aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if subscribing to the events failed
+    pub async fn subscribe_events<T, F, Fut>(
+        &self,
+        provider_id: Option<&str>,
+        topic: &str,
+        handler: F,
+    ) -> Result<JoinHandle<()>>
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(String, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let subject = provider_event_subject(&self.lattice, provider_id.unwrap_or("*"), topic);
+        self.subscribe(subject, event_handler(handler)).await
+    }
+
+    /// Run `task` every `period`, starting one `period` from now, until the provider shuts down
+    ///
+    /// Runs never overlap: if a run takes longer than `period`, the next one is delayed. On
+    /// shutdown, a run in progress is allowed to finish before [`Provider::shutdown`] is called,
+    /// for at most half of the host's provider shutdown delay, after which it is aborted. The
+    /// returned handle can be used to cancel the schedule earlier.
+    pub async fn schedule_interval<F, Fut>(&self, period: Duration, task: F) -> AbortHandle
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut stop = self.stop_scheduled_tasks.subscribe();
+        let handle = spawn(
+            async move {
+                let mut interval = interval_at(Instant::now() + period, period);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                loop {
+                    select! {
+                        _ = stop.wait_for(|stop| *stop) => break,
+                        _ = interval.tick() => task().await,
+                    }
+                }
+            }
+            .instrument(tracing::debug_span!("scheduled_task", ?period)),
+        );
+        self.track_scheduled_task(handle).await
+    }
+
+    /// Run `task` on `schedule` until the provider shuts down
+    ///
+    /// Runs never overlap and are drained on shutdown like those of
+    /// [`schedule_interval`](Self::schedule_interval). If `name` is given, the start of every run
+    /// is recorded on the lattice, so that a restarted provider resumes the schedule from its last
+    /// run instead of starting over, and makes up for a run missed while it was not running right
+    /// away. Names are scoped to the provider and must be valid key-value bucket keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bucket recording the runs of named schedules could not be opened
+    pub async fn schedule<F, Fut>(
+        &self,
+        schedule: Schedule,
+        name: Option<&str>,
+        task: F,
+    ) -> Result<AbortHandle>
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let runs = if let Some(name) = name {
+            let bucket = self
+                .kv_bucket(&schedule_bucket_name(&self.lattice))
+                .await
+                .context("failed to open the schedule bucket")?;
+            Some((bucket, format!("{}.{name}", self.provider_id)))
+        } else {
+            None
+        };
+        let span = tracing::debug_span!("scheduled_task", name = name.unwrap_or_default());
+        let handle = spawn(
+            run_schedule(schedule, runs, self.stop_scheduled_tasks.subscribe(), task)
+                .instrument(span),
+        );
+        Ok(self.track_scheduled_task(handle).await)
+    }
+
+    /// Keep track of a scheduled task, so that it is stopped on shutdown
+    async fn track_scheduled_task(&self, handle: JoinHandle<()>) -> AbortHandle {
+        let abort = handle.abort_handle();
+        let mut scheduled_tasks = self.scheduled_tasks.lock().await;
+        // Forget finished (e.g. aborted) tasks, so that the list does not grow without bound
+        scheduled_tasks.retain(|handle| !handle.is_finished());
+        scheduled_tasks.push(handle);
+        abort
+    }
+
+    /// Stop all scheduled tasks, waiting for runs in progress to finish for at most half of the
+    /// shutdown delay
+    pub(crate) async fn stop_scheduled_tasks(&self) {
+        self.stop_scheduled_tasks.send_replace(true);
+        // Release the lock before waiting, so that tasks scheduling further tasks do not deadlock
+        let mut handles = mem::take(&mut *self.scheduled_tasks.lock().await);
+        let drain = async {
+            for handle in &mut handles {
+                if let Err(err) = handle.await {
+                    if !err.is_cancelled() {
+                        error!(%err, "scheduled task failed");
+                    }
+                }
+            }
+        };
+        // Leave the provider the other half of the delay to shut down before the host
+        // terminates it
+        if timeout(self.shutdown_delay / 2, drain).await.is_err() {
+            warn!("scheduled tasks did not finish within the shutdown delay, aborting them");
+            for handle in &handles {
+                handle.abort();
+            }
+        }
+    }
+
     /// Get the provider key that was assigned to this host at startup
     #[must_use]
     pub fn provider_key(&self) -> &str {
         &self.provider_id
     }
 
+    /// Get the current merged configuration of this provider
+    ///
+    /// This starts out as the configuration received at initialization and reflects every
+    /// update accepted by [`Provider::receive_config_update`] since
+    pub async fn config(&self) -> HashMap<String, String> {
+        self.config.read().await.clone()
+    }
+
+    /// Replaces the stored configuration of this provider
+    pub(crate) async fn update_config(&self, config: HashMap<String, String>) {
+        *self.config.write().await = config;
+    }
+
     /// Stores link in the [ProviderConnection], either as a source link or target link
     /// depending on if the provider is the source or target of the link
     pub async fn put_link(&self, ld: InterfaceLinkDefinition) {
@@ -806,12 +1860,34 @@ impl ProviderConnection {
     /// based on if the provider is the source or target of the link
     pub async fn delete_link(&self, source_id: &str, target: &str) {
         if source_id == self.provider_id {
-            self.source_links.write().await.remove(source_id);
+            self.source_links.write().await.remove(target);
         } else if target == self.provider_id {
-            self.target_links.write().await.remove(target);
+            self.target_links.write().await.remove(source_id);
         }
     }
 
+    /// Returns the link between `source_id` and `target`, if this provider is part of it
+    pub(crate) async fn get_link(
+        &self,
+        source_id: &str,
+        target: &str,
+    ) -> Option<InterfaceLinkDefinition> {
+        if source_id == self.provider_id {
+            self.source_links.read().await.get(target).cloned()
+        } else if target == self.provider_id {
+            self.target_links.read().await.get(source_id).cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Returns all links of this provider, both as source and as target
+    pub(crate) async fn links(&self) -> Vec<InterfaceLinkDefinition> {
+        let mut links: Vec<_> = self.source_links.read().await.values().cloned().collect();
+        links.extend(self.target_links.read().await.values().cloned());
+        links
+    }
+
     /// Returns true if the source is linked to this provider or if the provider is linked to the target
     pub async fn is_linked(&self, source_id: &str, target_id: &str) -> bool {
         // Provider is the source of the link, so we check if the target is linked
@@ -828,7 +1904,7 @@ impl ProviderConnection {
 
     /// flush nats - called before main process exits
     pub(crate) async fn flush(&self) {
-        if let Err(err) = self.nats.flush().await {
+        if let Err(err) = self.nats().flush().await {
             error!(%err, "error flushing NATS client");
         }
     }
@@ -0,0 +1,59 @@
+//! Publishing and subscribing to custom events of providers on the lattice
+//!
+//! Providers publish JSON-encoded domain events (e.g. "bucket rotated") with
+//! [`ProviderConnection::publish_event`](crate::ProviderConnection::publish_event) on
+//! `wasmbus.provider_evt.<lattice>.<provider_id>.<topic>`, which keeps them separate from
+//! wRPC and control subjects and scoped to the lattice. Other providers receive them with
+//! [`ProviderConnection::subscribe_events`](crate::ProviderConnection::subscribe_events).
+//!
+//! Events are published with core NATS, so they are only delivered to current subscribers. To
+//! persist them, create a JetStream stream capturing the event subjects, which stores every event
+//! published on them.
+
+use core::future::Future;
+
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::FutureExt as _;
+use serde::de::DeserializeOwned;
+use tracing::warn;
+
+/// Generate the subject on which a provider publishes events of a topic
+///
+/// `provider_id` and `topic` may be `*` to match any provider or single-token topic.
+#[must_use]
+pub fn provider_event_subject(lattice: &str, provider_id: &str, topic: &str) -> String {
+    format!("wasmbus.provider_evt.{lattice}.{provider_id}.{topic}")
+}
+
+/// Build a handler for raw event messages, decoding them and passing the ID of the publishing
+/// provider along with the event to `handler`
+pub(crate) fn event_handler<T, F, Fut>(
+    handler: F,
+) -> impl Fn(Arc<async_nats::Client>, async_nats::Message) -> BoxFuture<'static, ()> + Send + 'static
+where
+    T: DeserializeOwned + Send + 'static,
+    F: Fn(String, T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let handler = Arc::new(handler);
+    move |_, msg| {
+        // The provider ID is the token following `wasmbus.provider_evt.<lattice>`
+        let provider_id = msg
+            .subject
+            .split('.')
+            .nth(3)
+            .unwrap_or_default()
+            .to_string();
+        let event = serde_json::from_slice::<T>(&msg.payload);
+        let handler = Arc::clone(&handler);
+        async move {
+            match event {
+                Ok(event) => handler(provider_id, event).await,
+                Err(err) => warn!(%err, subject = %msg.subject, "received invalid provider event"),
+            }
+        }
+        .boxed()
+    }
+}
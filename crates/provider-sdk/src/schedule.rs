@@ -0,0 +1,102 @@
+//! Recurring provider tasks run on an interval or a cron schedule
+//!
+//! [`ProviderConnection::schedule`](crate::ProviderConnection::schedule) runs a task on a
+//! [`Schedule`] until the provider shuts down. Named schedules record their runs in a NATS
+//! JetStream key-value bucket, so that a restarted provider resumes them where they left off.
+
+use core::future::Future;
+use core::str::FromStr;
+use core::time::Duration;
+
+use anyhow::Context as _;
+use chrono::{DateTime, TimeZone as _, Utc};
+use tokio::select;
+use tokio::sync::watch;
+use tokio::time::sleep;
+use tracing::{debug, warn};
+
+use crate::kv::KvBucket;
+
+/// When a scheduled task runs
+#[derive(Clone, Debug)]
+pub enum Schedule {
+    /// Every given period
+    Interval(Duration),
+    /// Whenever the cron expression matches, in UTC
+    Cron(Box<cron::Schedule>),
+}
+
+impl Schedule {
+    /// Parse a cron expression with a leading seconds field, e.g. `0 */5 * * * *` to run every
+    /// five minutes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `expression` is not a valid cron expression
+    pub fn cron(expression: &str) -> anyhow::Result<Self> {
+        let schedule = cron::Schedule::from_str(expression)
+            .with_context(|| format!("invalid cron expression `{expression}`"))?;
+        Ok(Self::Cron(Box::new(schedule)))
+    }
+
+    /// The first run of the schedule after `time`, if there is one
+    fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Interval(period) => {
+                let period = chrono::Duration::from_std(*period).ok()?;
+                time.checked_add_signed(period)
+            }
+            Self::Cron(schedule) => schedule.after(&time).next(),
+        }
+    }
+}
+
+/// Name of the bucket recording the runs of named schedules of providers on `lattice`
+pub(crate) fn schedule_bucket_name(lattice: &str) -> String {
+    format!("PROVIDER_SCHEDULES_{lattice}")
+}
+
+/// Run `task` on `schedule` until `stop` is set
+///
+/// If `runs` is given, the start of every run is recorded in it under the given key, and the
+/// schedule resumes from the last recorded run. A run that fell due in the meantime happens right
+/// away.
+pub(crate) async fn run_schedule<F, Fut>(
+    schedule: Schedule,
+    runs: Option<(KvBucket<i64>, String)>,
+    mut stop: watch::Receiver<bool>,
+    task: F,
+) where
+    F: Fn() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let last_run = match &runs {
+        Some((runs, key)) => match runs.get(key).await {
+            Ok(last_run) => last_run.and_then(|ms| Utc.timestamp_millis_opt(ms).single()),
+            Err(err) => {
+                warn!(%err, %key, "failed to read the last run of the schedule, starting over");
+                None
+            }
+        },
+        None => None,
+    };
+    let mut next = schedule.next_after(last_run.unwrap_or_else(Utc::now));
+    while let Some(at) = next {
+        let delay = (at - Utc::now()).to_std().unwrap_or_default();
+        select! {
+            _ = stop.wait_for(|stop| *stop) => return,
+            () = sleep(delay) => {}
+        }
+        let started = Utc::now();
+        if let Some((runs, key)) = &runs {
+            if let Err(err) = runs.put(key, &started.timestamp_millis()).await {
+                warn!(%err, %key, "failed to record the run of the schedule");
+            }
+        }
+        task().await;
+        // Runs that fell due while this one was in progress are skipped. The timer may fire
+        // marginally early, so never schedule a run before the one that just happened
+        next = schedule.next_after(started.max(at));
+    }
+    debug!("schedule has no further runs");
+}
@@ -0,0 +1,215 @@
+//! Resolving references to secrets in provider configuration
+//!
+//! Instead of putting secret values into plain configuration, links may reference secrets by name
+//! with config keys ending in [`SECRET_REFERENCE_SUFFIX`], e.g. `password_secret: my-key`. Providers
+//! started with [`run_provider_with_secrets`](crate::run_provider_with_secrets) receive such
+//! references already resolved in [`LinkConfig::secrets`]. Otherwise they can be resolved with a
+//! [`SecretsResolver`] by [`LinkConfig::resolve_secrets`] or [`resolve_secret_references`].
+
+use core::future::Future;
+
+use std::collections::HashMap;
+use std::env;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{bail, Context as _};
+use futures::future::BoxFuture;
+
+use crate::{LinkConfig, SecretValue};
+
+/// Suffix of config keys whose value is the name of a secret to resolve
+pub const SECRET_REFERENCE_SUFFIX: &str = "_secret";
+
+/// A backend that secrets referenced in provider configuration can be resolved from
+pub trait SecretsResolver: Send + Sync {
+    /// Resolve the secret named `name`, returning `None` if it does not exist
+    fn resolve(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = anyhow::Result<Option<SecretValue>>> + Send;
+}
+
+/// Object-safe form of [`SecretsResolver`], so that the SDK can store the resolver of a provider
+trait DynSecretsResolver: Send + Sync {
+    fn resolve_dyn<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<Option<SecretValue>>>;
+}
+
+impl<T: SecretsResolver> DynSecretsResolver for T {
+    fn resolve_dyn<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<Option<SecretValue>>> {
+        Box::pin(self.resolve(name))
+    }
+}
+
+/// The [`SecretsResolver`] a provider was started with, used to resolve the secrets referenced in
+/// the config of every link before the provider receives it
+#[derive(Clone)]
+pub(crate) struct ProviderSecretsResolver(Arc<dyn DynSecretsResolver>);
+
+impl ProviderSecretsResolver {
+    pub(crate) fn new(resolver: impl SecretsResolver + 'static) -> Self {
+        Self(Arc::new(resolver))
+    }
+}
+
+impl SecretsResolver for ProviderSecretsResolver {
+    async fn resolve(&self, name: &str) -> anyhow::Result<Option<SecretValue>> {
+        self.0.resolve_dyn(name).await
+    }
+}
+
+/// Resolves secrets from files in a directory, named after the secret, such as Kubernetes secrets
+/// mounted as a volume
+///
+/// Files with UTF-8 contents resolve to [`SecretValue::String`], others to [`SecretValue::Bytes`].
+#[derive(Clone, Debug)]
+pub struct FileSecretsResolver {
+    dir: PathBuf,
+}
+
+impl FileSecretsResolver {
+    /// Create a resolver reading secrets from files in `dir`
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl SecretsResolver for FileSecretsResolver {
+    async fn resolve(&self, name: &str) -> anyhow::Result<Option<SecretValue>> {
+        // Only allow plain file names, so that references cannot escape the secrets directory
+        if !matches!(
+            Path::new(name).components().collect::<Vec<_>>().as_slice(),
+            [Component::Normal(_)]
+        ) {
+            bail!("invalid secret name `{name}`");
+        }
+        match tokio::fs::read(self.dir.join(name)).await {
+            Ok(bytes) => Ok(Some(match String::from_utf8(bytes) {
+                Ok(s) => SecretValue::String(s),
+                Err(err) => SecretValue::Bytes(err.into_bytes()),
+            })),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read secret `{name}`")),
+        }
+    }
+}
+
+/// Resolves secrets from environment variables of the provider process, named after the secret
+/// with an optional prefix
+///
+/// Note that hosts start providers with an empty environment apart from a few variables, so this
+/// is mostly useful for providers run outside of a host, e.g. in tests.
+#[derive(Clone, Debug, Default)]
+pub struct EnvSecretsResolver {
+    prefix: String,
+}
+
+impl EnvSecretsResolver {
+    /// Create a resolver reading secret `name` from the environment variable `{prefix}{name}`
+    #[must_use]
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl SecretsResolver for EnvSecretsResolver {
+    async fn resolve(&self, name: &str) -> anyhow::Result<Option<SecretValue>> {
+        match env::var(format!("{}{name}", self.prefix)) {
+            Ok(value) => Ok(Some(SecretValue::String(value))),
+            Err(env::VarError::NotPresent) => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read secret `{name}`")),
+        }
+    }
+}
+
+/// Resolves secrets from a HashiCorp Vault KV version 2 secrets engine
+///
+/// Secret names have the form `{path}#{key}` and resolve to the value of `key` in the secret
+/// stored at `path` of the engine, e.g. `databases/orders#password`.
+#[cfg(feature = "vault")]
+#[derive(Clone)]
+pub struct VaultSecretsResolver {
+    client: Arc<vaultrs::client::VaultClient>,
+    mount: String,
+}
+
+#[cfg(feature = "vault")]
+impl VaultSecretsResolver {
+    /// Create a resolver reading secrets with `client` from the KV version 2 secrets engine
+    /// mounted at `mount`, e.g. `secret`
+    #[must_use]
+    pub fn new(client: vaultrs::client::VaultClient, mount: impl Into<String>) -> Self {
+        Self {
+            client: Arc::new(client),
+            mount: mount.into(),
+        }
+    }
+}
+
+#[cfg(feature = "vault")]
+impl SecretsResolver for VaultSecretsResolver {
+    async fn resolve(&self, name: &str) -> anyhow::Result<Option<SecretValue>> {
+        let Some((path, key)) = name.rsplit_once('#') else {
+            bail!("invalid secret name `{name}`, expected `path#key`");
+        };
+        match vaultrs::kv2::read::<HashMap<String, String>>(self.client.as_ref(), &self.mount, path)
+            .await
+        {
+            Ok(mut data) => Ok(data.remove(key).map(SecretValue::String)),
+            Err(vaultrs::error::ClientError::APIError { code: 404, .. }) => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read secret `{name}`")),
+        }
+    }
+}
+
+/// Resolve every value of `config` whose key ends in [`SECRET_REFERENCE_SUFFIX`] as the name of a
+/// secret, keyed by the config key without the suffix
+///
+/// For example, `password_secret: my-key` resolves secret `my-key` as `password`.
+///
+/// # Errors
+///
+/// Returns an error if a referenced secret does not exist or could not be resolved
+pub async fn resolve_secret_references(
+    config: &HashMap<String, String>,
+    resolver: &impl SecretsResolver,
+) -> anyhow::Result<HashMap<String, SecretValue>> {
+    let mut secrets = HashMap::new();
+    for (key, name) in config {
+        let Some(key) = key.strip_suffix(SECRET_REFERENCE_SUFFIX) else {
+            continue;
+        };
+        let secret = resolver
+            .resolve(name)
+            .await
+            .with_context(|| format!("failed to resolve secret for `{key}`"))?
+            .with_context(|| format!("secret `{name}` referenced by `{key}` does not exist"))?;
+        secrets.insert(key.to_string(), secret);
+    }
+    Ok(secrets)
+}
+
+impl LinkConfig<'_> {
+    /// Resolve the secrets referenced in the link config with `resolver` (see
+    /// [`resolve_secret_references`])
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a referenced secret does not exist or could not be resolved
+    pub async fn resolve_secrets(
+        &self,
+        resolver: &impl SecretsResolver,
+    ) -> anyhow::Result<HashMap<String, SecretValue>> {
+        resolve_secret_references(self.config, resolver).await
+    }
+}
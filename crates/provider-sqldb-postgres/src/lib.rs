@@ -16,7 +16,7 @@ use tokio_postgres::Statement;
 use tracing::{error, instrument, warn};
 use ulid::Ulid;
 
-use wasmcloud_provider_sdk::{get_connection, run_provider, LinkConfig, Provider};
+use wasmcloud_provider_sdk::{get_connection, run_provider, LinkConfig, LinkDeleteInfo, Provider};
 
 mod bindings;
 use bindings::{
@@ -211,7 +211,10 @@ impl Provider for PostgresProvider {
     ///
     /// Generally we can release the resources (connections) associated with the source
     #[instrument(level = "debug", skip(self))]
-    async fn delete_link(&self, source_id: &str) -> anyhow::Result<()> {
+    async fn delete_link_as_target(
+        &self,
+        LinkDeleteInfo { source_id, .. }: LinkDeleteInfo<'_>,
+    ) -> anyhow::Result<()> {
         let mut prepared_statements = self.prepared_statements.write().await;
         prepared_statements.retain(|_stmt_token, (_conn, src_id)| source_id != *src_id);
         drop(prepared_statements);
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+type Forwarder = Box<dyn Fn(ForwardedLog) + Send + Sync>;
+
+/// Log forwarder registered with [`set_log_forwarder`], if any
+static LOG_FORWARDER: RwLock<Option<RegisteredForwarder>> = RwLock::new(None);
+
+/// Source of the IDs telling registrations of log forwarders apart
+static NEXT_FORWARDER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Targets whose records are never forwarded, since forwarding logs through them would log again
+const IGNORED_TARGET_PREFIXES: [&str; 1] = ["async_nats"];
+
+struct RegisteredForwarder {
+    id: u64,
+    min_level: Level,
+    forwarder: Forwarder,
+}
+
+/// A log record passed to the callback registered with [`set_log_forwarder`]
+#[derive(Clone, Debug, Serialize)]
+pub struct ForwardedLog {
+    /// Time the record was emitted, in milliseconds since the Unix epoch
+    pub timestamp_ms: u128,
+    /// Level of the record, e.g. `WARN`
+    pub level: String,
+    /// Target of the record, usually the module path it was emitted from
+    pub target: String,
+    /// Message of the record
+    pub message: String,
+    /// Other fields of the record, formatted as strings
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, String>,
+}
+
+/// Registers a callback that is passed every log record of the global subscriber configured by
+/// this crate at `min_level` or above (e.g. `WARN` for warnings and errors), in addition to
+/// logging it as usual, replacing the callback registered before, if any
+///
+/// The callback is unregistered when the returned guard is dropped, unless it was replaced in
+/// the meantime. It is called synchronously while logging, so it should only hand the record
+/// off, and must not log itself. Records of `async_nats` are not passed to it.
+#[must_use = "the log forwarder is unregistered when the guard is dropped"]
+pub fn set_log_forwarder(
+    min_level: Level,
+    forwarder: impl Fn(ForwardedLog) + Send + Sync + 'static,
+) -> LogForwarderGuard {
+    let id = NEXT_FORWARDER_ID.fetch_add(1, Ordering::Relaxed);
+    let previous = LOG_FORWARDER
+        .write()
+        .unwrap_or_else(|err| err.into_inner())
+        .replace(RegisteredForwarder {
+            id,
+            min_level,
+            forwarder: Box::new(forwarder),
+        });
+    // Drop the previous forwarder outside of the lock, since dropping it may log
+    drop(previous);
+    LogForwarderGuard { id }
+}
+
+/// Guard unregistering the log forwarder registered with [`set_log_forwarder`] when dropped
+#[derive(Debug)]
+pub struct LogForwarderGuard {
+    id: u64,
+}
+
+impl Drop for LogForwarderGuard {
+    fn drop(&mut self) {
+        let mut registered = LOG_FORWARDER.write().unwrap_or_else(|err| err.into_inner());
+        let current = registered
+            .as_ref()
+            .is_some_and(|forwarder| forwarder.id == self.id);
+        let previous = if current { registered.take() } else { None };
+        drop(registered);
+        drop(previous);
+    }
+}
+
+/// Layer passing log records to the forwarder registered with [`set_log_forwarder`], if any
+pub(crate) struct LogForwardLayer;
+
+impl<S> Layer<S> for LogForwardLayer
+where
+    S: Subscriber,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Ok(registered) = LOG_FORWARDER.read() else {
+            return;
+        };
+        let Some(RegisteredForwarder {
+            min_level,
+            forwarder,
+            ..
+        }) = registered.as_ref()
+        else {
+            return;
+        };
+        let metadata = event.metadata();
+        // More verbose levels compare as greater
+        if metadata.level() > min_level
+            || IGNORED_TARGET_PREFIXES
+                .iter()
+                .any(|prefix| metadata.target().starts_with(prefix))
+        {
+            return;
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        forwarder(ForwardedLog {
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or_default(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: HashMap<String, String>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields
+                .insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+}
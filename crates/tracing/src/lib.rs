@@ -12,7 +12,11 @@ use wasmcloud_core::OtelConfig;
 #[cfg(feature = "otel")]
 pub mod context;
 
+mod forward;
+pub use forward::{set_log_forwarder, ForwardedLog, LogForwarderGuard};
+
 mod traces;
+pub use traces::set_log_filter;
 
 mod metrics;
 
@@ -10,12 +10,14 @@ use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::fmt::format::{DefaultFields, Format, Full, Json, JsonFields, Writer};
 use tracing_subscriber::fmt::time::SystemTime;
 use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
-use tracing_subscriber::layer::{Layered, SubscriberExt};
+use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::registry::LookupSpan;
-use tracing_subscriber::{EnvFilter, Layer, Registry};
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
 use wasmcloud_core::logging::Level;
 use wasmcloud_core::OtelConfig;
 
+use crate::forward::LogForwardLayer;
+
 struct LockedWriter<'a> {
     stderr: StderrLock<'a>,
 }
@@ -48,6 +50,9 @@ impl<'a> Drop for LockedWriter<'a> {
 
 static STDERR: OnceCell<std::io::Stderr> = OnceCell::new();
 
+/// Handle to the level filter of the global subscriber, used to change it at runtime
+static LEVEL_FILTER: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+
 #[cfg(feature = "otel")]
 static LOG_PROVIDER: OnceCell<opentelemetry_sdk::logs::LoggerProvider> = OnceCell::new();
 
@@ -81,6 +86,7 @@ where
 
 /// Configures a global tracing subscriber, which includes:
 /// - A level filter, which forms the base and applies to all other layers
+/// - A layer forwarding log records to the callback registered with `set_log_forwarder`, if any
 /// - A local logging layer, which is either plaintext or structured (JSON)
 ///
 /// # Errors
@@ -99,15 +105,21 @@ pub fn configure_tracing(
         .map_err(|_| anyhow::anyhow!("stderr already initialized"))?;
 
     let base_reg = tracing_subscriber::Registry::default();
-    let level_filter = get_level_filter(log_level_override);
+    let level_filter = get_reloadable_level_filter(log_level_override);
 
     let res = if structured_logging_enabled {
         let log_layer = get_json_log_layer()?;
-        let layered = base_reg.with(level_filter).with(log_layer);
+        let layered = base_reg
+            .with(level_filter)
+            .with(LogForwardLayer)
+            .with(log_layer);
         tracing::subscriber::set_global_default(layered)
     } else {
         let log_layer = get_plaintext_log_layer()?;
-        let layered = base_reg.with(level_filter).with(log_layer);
+        let layered = base_reg
+            .with(level_filter)
+            .with(LogForwardLayer)
+            .with(log_layer);
         tracing::subscriber::set_global_default(layered)
     };
 
@@ -116,6 +128,7 @@ pub fn configure_tracing(
 
 /// Configures a global tracing subscriber, which includes:
 /// - A level filter, which forms the base and applies to all other layers
+/// - A layer forwarding log records to the callback registered with `set_log_forwarder`, if any
 /// - OTEL tracing and logging layers, if OTEL configuration is provided
 /// - A local logging layer, which is either plaintext or structured (JSON)
 ///
@@ -144,7 +157,7 @@ pub fn configure_tracing(
 
     let base_reg = tracing_subscriber::Registry::default();
 
-    let level_filter = get_level_filter(log_level_override);
+    let level_filter = get_reloadable_level_filter(log_level_override);
 
     let normalized_service_name = service_name.to_string();
 
@@ -164,6 +177,7 @@ pub fn configure_tracing(
         (true, true, true) => {
             let layered = base_reg
                 .with(level_filter)
+                .with(LogForwardLayer)
                 .with(get_json_log_layer()?)
                 .with(get_otel_tracing_layer(
                     &traces_endpoint,
@@ -178,6 +192,7 @@ pub fn configure_tracing(
         (true, true, false) => {
             let layered = base_reg
                 .with(level_filter)
+                .with(LogForwardLayer)
                 .with(get_plaintext_log_layer()?)
                 .with(get_otel_tracing_layer(
                     &traces_endpoint,
@@ -192,6 +207,7 @@ pub fn configure_tracing(
         (true, false, true) => {
             let layered = base_reg
                 .with(level_filter)
+                .with(LogForwardLayer)
                 .with(get_json_log_layer()?)
                 .with(get_otel_tracing_layer(
                     &traces_endpoint,
@@ -202,6 +218,7 @@ pub fn configure_tracing(
         (true, false, false) => {
             let layered = base_reg
                 .with(level_filter)
+                .with(LogForwardLayer)
                 .with(get_plaintext_log_layer()?)
                 .with(get_otel_tracing_layer(
                     &traces_endpoint,
@@ -212,6 +229,7 @@ pub fn configure_tracing(
         (false, true, true) => {
             let layered = base_reg
                 .with(level_filter)
+                .with(LogForwardLayer)
                 .with(get_json_log_layer()?)
                 .with(get_otel_logging_layer(
                     &logs_endpoint,
@@ -222,6 +240,7 @@ pub fn configure_tracing(
         (false, true, false) => {
             let layered = base_reg
                 .with(level_filter)
+                .with(LogForwardLayer)
                 .with(get_plaintext_log_layer()?)
                 .with(get_otel_logging_layer(
                     &logs_endpoint,
@@ -230,11 +249,17 @@ pub fn configure_tracing(
             tracing::subscriber::set_global_default(layered)
         }
         (false, false, true) => {
-            let layered = base_reg.with(level_filter).with(get_json_log_layer()?);
+            let layered = base_reg
+                .with(level_filter)
+                .with(LogForwardLayer)
+                .with(get_json_log_layer()?);
             tracing::subscriber::set_global_default(layered)
         }
         (false, false, false) => {
-            let layered = base_reg.with(level_filter).with(get_plaintext_log_layer()?);
+            let layered = base_reg
+                .with(level_filter)
+                .with(LogForwardLayer)
+                .with(get_plaintext_log_layer()?);
             tracing::subscriber::set_global_default(layered)
         }
     };
@@ -259,12 +284,17 @@ where
         builder = builder.with_endpoint(endpoint);
     }
 
+    // Respect a sampler configured with `OTEL_TRACES_SAMPLER`, otherwise sample every trace
+    let mut trace_config = opentelemetry_sdk::trace::config();
+    if env::var_os("OTEL_TRACES_SAMPLER").is_none() {
+        trace_config = trace_config.with_sampler(opentelemetry_sdk::trace::Sampler::AlwaysOn);
+    }
+
     let tracer = opentelemetry_otlp::new_pipeline()
         .tracing()
         .with_exporter(builder)
         .with_trace_config(
-            opentelemetry_sdk::trace::config()
-                .with_sampler(opentelemetry_sdk::trace::Sampler::AlwaysOn)
+            trace_config
                 .with_id_generator(opentelemetry_sdk::trace::RandomIdGenerator::default())
                 .with_max_events_per_span(64)
                 .with_max_attributes_per_span(16)
@@ -333,7 +363,11 @@ where
         .fmt_fields(DefaultFields::new()))
 }
 
-fn get_json_log_layer() -> anyhow::Result<impl Layer<Layered<EnvFilter, Registry>>> {
+fn get_json_log_layer<S>() -> anyhow::Result<impl Layer<S>>
+where
+    S: Subscriber,
+    S: for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
     let stderr = STDERR.get().context("stderr not initialized")?;
     Ok(tracing_subscriber::fmt::layer()
         .with_writer(LockedWriter::new)
@@ -342,6 +376,32 @@ fn get_json_log_layer() -> anyhow::Result<impl Layer<Layered<EnvFilter, Registry
         .fmt_fields(JsonFields::new()))
 }
 
+/// Wraps the level filter in a layer that can be changed at runtime with [`set_log_filter`]
+fn get_reloadable_level_filter(
+    log_level_override: Option<&Level>,
+) -> reload::Layer<EnvFilter, Registry> {
+    let (level_filter, handle) = reload::Layer::new(get_level_filter(log_level_override));
+    // Only the first call to `configure_tracing` can succeed, so a handle that fails to be set
+    // belongs to a subscriber that is never installed
+    let _ = LEVEL_FILTER.set(handle);
+    level_filter
+}
+
+/// Replaces the level filter of the global subscriber configured by this crate with the given
+/// directives, which use the same syntax as `RUST_LOG` (e.g. `info,wasmcloud_provider_sdk=debug`)
+///
+/// # Errors
+///
+/// Returns an error if the directives are invalid or tracing has not been configured
+pub fn set_log_filter(directives: &str) -> anyhow::Result<()> {
+    let filter = EnvFilter::try_new(directives).context("invalid log filter directives")?;
+    LEVEL_FILTER
+        .get()
+        .context("tracing has not been configured")?
+        .reload(filter)
+        .context("failed to reload log filter")
+}
+
 fn get_level_filter(log_level_override: Option<&Level>) -> EnvFilter {
     if let Some(log_level) = log_level_override {
         let level = wasi_level_to_tracing_level(log_level);
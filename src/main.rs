@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 use std::env;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -58,6 +59,15 @@ struct Args {
     /// Delay, in milliseconds, between requesting a provider shut down and forcibly terminating its process
     #[clap(long = "provider-shutdown-delay", default_value = "300", env = "WASMCLOUD_PROV_SHUTDOWN_DELAY_MS", value_parser = parse_duration)]
     provider_shutdown_delay: Duration,
+    /// Maximum number of attempts providers make to reconnect to NATS after losing their RPC connection, defaults to the NATS client default if not supplied
+    #[clap(
+        long = "provider-rpc-max-reconnects",
+        env = "WASMCLOUD_PROV_RPC_MAX_RECONNECTS"
+    )]
+    provider_rpc_max_reconnects: Option<usize>,
+    /// Maximum delay, in milliseconds, between attempts of providers to reconnect to NATS after losing their RPC connection, defaults to the NATS client default if not supplied
+    #[clap(long = "provider-rpc-reconnect-delay-max", env = "WASMCLOUD_PROV_RPC_RECONNECT_DELAY_MAX_MS", value_parser = parse_duration)]
+    provider_rpc_reconnect_delay_max: Option<Duration>,
     /// Determines whether OCI images tagged latest are allowed to be pulled from OCI registries and started
     #[clap(long = "allow-latest", env = "WASMCLOUD_OCI_ALLOW_LATEST")]
     allow_latest: bool,
@@ -135,6 +145,14 @@ struct Args {
     /// A port to use to connect to NATS for RPC messages, defaults to the value supplied to --nats-port if not supplied
     #[clap(long = "rpc-port", env = "WASMCLOUD_RPC_PORT", hide = true)]
     rpc_port: Option<u16>,
+    /// Further NATS URLs to fail over to for RPC messages (e.g. other leaf node seeds), can be specified multiple times. Also used by providers
+    #[clap(
+        long = "rpc-failover-url",
+        env = "WASMCLOUD_RPC_FAILOVER_URLS",
+        value_delimiter = ',',
+        hide = true
+    )]
+    rpc_failover_urls: Vec<Url>,
     /// A user JWT to use to authenticate to NATS for RPC messages, defaults to the value supplied to --nats-jwt if not supplied
     #[clap(
         long = "rpc-jwt",
@@ -157,6 +175,33 @@ struct Args {
     /// Optional flag to require host communication over TLS with a NATS server for RPC messages
     #[clap(long = "rpc-tls", env = "WASMCLOUD_RPC_TLS", hide = true)]
     rpc_tls: bool,
+    /// A NATS credentials file to use to authenticate to NATS for RPC messages, takes precedence over --rpc-jwt and --rpc-seed. Also used by providers
+    #[clap(long = "rpc-credsfile", env = "WASMCLOUD_RPC_CREDSFILE", hide = true)]
+    rpc_credsfile: Option<PathBuf>,
+    /// PEM-encoded root certificates to trust for RPC connections to NATS, in addition to the system roots. Also used by providers
+    #[clap(
+        long = "rpc-tls-ca-file",
+        env = "WASMCLOUD_RPC_TLS_CA_FILE",
+        value_delimiter = ',',
+        hide = true
+    )]
+    rpc_tls_ca_files: Vec<PathBuf>,
+    /// A PEM-encoded client certificate to use to authenticate to NATS for RPC messages with mutual TLS. Also used by providers, which read it again whenever they reconnect
+    #[clap(
+        long = "rpc-tls-client-cert",
+        env = "WASMCLOUD_RPC_TLS_CLIENT_CERT",
+        requires = "rpc_tls_client_key",
+        hide = true
+    )]
+    rpc_tls_client_cert: Option<PathBuf>,
+    /// The PEM-encoded private key of the certificate supplied to --rpc-tls-client-cert
+    #[clap(
+        long = "rpc-tls-client-key",
+        env = "WASMCLOUD_RPC_TLS_CLIENT_KEY",
+        requires = "rpc_tls_client_cert",
+        hide = true
+    )]
+    rpc_tls_client_key: Option<PathBuf>,
 
     /// If provided, enables policy checks on start actions and component invocations
     #[clap(long = "policy-topic", env = "WASMCLOUD_POLICY_TOPIC")]
@@ -355,16 +400,23 @@ async fn main() -> anyhow::Result<()> {
         js_domain: args.js_domain,
         labels,
         provider_shutdown_delay: Some(args.provider_shutdown_delay),
+        provider_rpc_max_reconnects: args.provider_rpc_max_reconnects,
+        provider_rpc_reconnect_delay_max: args.provider_rpc_reconnect_delay_max,
         oci_opts,
         ctl_jwt: args.ctl_jwt.or_else(|| args.nats_jwt.clone()),
         ctl_key: ctl_key.or_else(|| nats_key.clone()),
         ctl_tls: args.ctl_tls,
         ctl_topic_prefix: args.ctl_topic_prefix,
         rpc_nats_url,
+        rpc_nats_failover_urls: args.rpc_failover_urls,
         rpc_timeout: args.rpc_timeout_ms,
         rpc_jwt: args.rpc_jwt.or_else(|| args.nats_jwt.clone()),
         rpc_key: rpc_key.or_else(|| nats_key.clone()),
         rpc_tls: args.rpc_tls,
+        rpc_credsfile: args.rpc_credsfile,
+        rpc_tls_ca_files: args.rpc_tls_ca_files,
+        rpc_tls_client_cert: args.rpc_tls_client_cert,
+        rpc_tls_client_key: args.rpc_tls_client_key,
         allow_file_load: args.allow_file_load,
         log_level,
         enable_structured_logging: args.enable_structured_logging,